@@ -0,0 +1,31 @@
+//! `arbitrary::Arbitrary` for UUID, to fuzz code which consumes UUIDs.
+
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::UUID;
+
+impl<'a> Arbitrary<'a> for UUID {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(UUID(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 16] as Arbitrary>::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arbitrary() {
+        let data = UUID::NAMESPACE_DNS.0;
+        let mut u = Unstructured::new(&data);
+        assert_eq!(UUID::arbitrary(&mut u), Ok(UUID::NAMESPACE_DNS));
+        assert!(u.is_empty());
+        assert_eq!(UUID::size_hint(0), (16, Some(16)));
+    }
+}
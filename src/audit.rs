@@ -0,0 +1,261 @@
+#[cfg(feature = "std")]
+use crate::Timestamp;
+use crate::{Variant, Version, UUID};
+
+/// A structural issue found by [`UUID::audit`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Anomaly {
+    /// The version nibble is not a known version.
+    UnknownVersion,
+    /// The variant is not the one specified in rfc4122 document.
+    NonRfcVariant,
+    /// The random bits of a version-4 UUID are heavily biased.
+    SuspiciousEntropy,
+    /// The embedded timestamp is later than the current system time.
+    FutureTimestamp,
+}
+
+/// A best-effort guess of how a UUID was generated, see [`UUID::fingerprint_generator`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GeneratorHint {
+    /// The nil UUID.
+    Nil,
+    /// The max UUID.
+    Max,
+    /// Time based (v1) with a real IEEE 802 MAC address as node.
+    TimeWithMac,
+    /// Time based (v1) with a random node, the multicast bit set.
+    TimeWithRandomNode,
+    /// DCE security (v2).
+    Dce,
+    /// Name based (v3 or v5), hashed from a namespace and a name.
+    NameBased,
+    /// Random (v4) with 122 bits of randomness.
+    Random,
+    /// Reordered time based (v6).
+    ReorderedTime,
+    /// Unix time ordered (v7).
+    UnixTime,
+    /// Custom application data (v8).
+    Custom,
+    /// Not an rfc4122 variant, or an unknown version.
+    Unknown,
+}
+
+/// A best-effort guess of which convention a UUID came from, see [`UUID::classify_source`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SourceGuess {
+    /// The Microsoft variant, likely a COM GUID stored in mixed-endian order.
+    Guid,
+    /// The rfc4122 variant, stored in big-endian order.
+    Uuid,
+    /// A reserved NCS or future variant.
+    Unknown,
+}
+
+impl UUID {
+    /// Guess from the variant bits whether this value originated as a COM
+    /// GUID, which informs whether the mixed-endian swap applies.
+    pub fn classify_source(&self) -> SourceGuess {
+        match self.decode().get_variant() {
+            Some(Variant::MS) => SourceGuess::Guid,
+            Some(Variant::RFC) => SourceGuess::Uuid,
+            _ => SourceGuess::Unknown,
+        }
+    }
+
+    /// Guess how this UUID was generated from its version, variant and node
+    /// bits. This is a heuristic, it can't tell apart generators which
+    /// produce the same bit patterns.
+    pub fn fingerprint_generator(&self) -> GeneratorHint {
+        if self.is_nil() {
+            return GeneratorHint::Nil;
+        }
+        if self.is_max() {
+            return GeneratorHint::Max;
+        }
+
+        let layout = self.decode();
+        if layout.get_variant() != Some(Variant::RFC) {
+            return GeneratorHint::Unknown;
+        }
+        match layout.get_version() {
+            Some(Version::TIME) if layout.node[0] & 0x01 == 0x01 => {
+                GeneratorHint::TimeWithRandomNode
+            }
+            Some(Version::TIME) => GeneratorHint::TimeWithMac,
+            Some(Version::DCE) => GeneratorHint::Dce,
+            Some(Version::MD5) | Some(Version::SHA1) => GeneratorHint::NameBased,
+            Some(Version::RAND) => GeneratorHint::Random,
+            Some(Version::REORDERED) => GeneratorHint::ReorderedTime,
+            Some(Version::UNIX) => GeneratorHint::UnixTime,
+            Some(Version::CUSTOM) => GeneratorHint::Custom,
+            None => GeneratorHint::Unknown,
+        }
+    }
+
+    /// Report all structural anomalies found in this UUID, empty when none.
+    #[cfg(feature = "std")]
+    pub fn audit(&self) -> Vec<Anomaly> {
+        let layout = self.decode();
+        let mut anomalies = Vec::new();
+
+        let version = layout.get_version();
+        if version.is_none() {
+            anomalies.push(Anomaly::UnknownVersion);
+        }
+        if layout.get_variant() != Some(Variant::RFC) {
+            anomalies.push(Anomaly::NonRfcVariant);
+        }
+
+        match version {
+            Some(Version::RAND) => {
+                // 122 random bits average 61 set bits; anything beyond five
+                // standard deviations is very unlikely to come from a RNG.
+                let ones = (self.as_u128() & !(0xf << 76 | 0x3 << 62)).count_ones();
+                if !(34..=88).contains(&ones) {
+                    anomalies.push(Anomaly::SuspiciousEntropy);
+                }
+            }
+            Some(Version::TIME) | Some(Version::REORDERED) => {
+                let (low, mid, high) = (
+                    layout.field_low as u64,
+                    layout.field_mid as u64,
+                    (layout.field_high_and_version & 0xfff) as u64,
+                );
+                let ticks = match version {
+                    Some(Version::TIME) => high << 48 | mid << 32 | low,
+                    _ => low << 28 | mid << 12 | high,
+                };
+                if ticks > Timestamp::new().0 {
+                    anomalies.push(Anomaly::FutureTimestamp);
+                }
+            }
+            Some(Version::UNIX) => {
+                let now = Timestamp::new().0.saturating_sub(crate::UTC_EPOCH) / 10_000;
+                if self.unix_millis().is_some_and(|t| t > now) {
+                    anomalies.push(Anomaly::FutureTimestamp);
+                }
+            }
+            _ => {}
+        }
+
+        anomalies
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fingerprint_generator() {
+        assert_eq!(UUID::nil().fingerprint_generator(), GeneratorHint::Nil);
+        assert_eq!(UUID::max().fingerprint_generator(), GeneratorHint::Max);
+        assert_eq!(
+            UUID::NAMESPACE_DNS.fingerprint_generator(),
+            GeneratorHint::TimeWithMac
+        );
+        assert_eq!(
+            UUID::v8([0x5a; 16]).to_uuid().fingerprint_generator(),
+            GeneratorHint::Custom
+        );
+        assert_eq!(
+            UUID([0x5a; 16]).fingerprint_generator(),
+            GeneratorHint::Unknown
+        );
+    }
+
+    #[test]
+    fn test_classify_source() {
+        let mut bytes = *UUID::NAMESPACE_DNS.as_bytes();
+        assert_eq!(UUID(bytes).classify_source(), SourceGuess::Uuid);
+        bytes[8] = 0xc0;
+        assert_eq!(UUID(bytes).classify_source(), SourceGuess::Guid);
+        bytes[8] = 0x00;
+        assert_eq!(UUID(bytes).classify_source(), SourceGuess::Unknown);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_fingerprint_random() {
+        assert_eq!(
+            UUID::v4().to_uuid().fingerprint_generator(),
+            GeneratorHint::Random
+        );
+        assert_eq!(
+            UUID::v7().to_uuid().fingerprint_generator(),
+            GeneratorHint::UnixTime
+        );
+    }
+
+    #[cfg(feature = "mac")]
+    #[test]
+    fn test_fingerprint_time() {
+        let _guard = crate::time::STATE_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let node = [0x03, 0x2a, 0x35, 0x0d, 0x13, 0x80];
+        assert_eq!(
+            UUID::from_mac(Version::TIME, node)
+                .to_uuid()
+                .fingerprint_generator(),
+            GeneratorHint::TimeWithRandomNode
+        );
+        assert_eq!(
+            UUID::v6().to_uuid().fingerprint_generator(),
+            GeneratorHint::ReorderedTime
+        );
+    }
+
+    #[cfg(all(feature = "hmd5", feature = "hsha1"))]
+    #[test]
+    fn test_fingerprint_name() {
+        for uuid in [
+            UUID::v3("name", UUID::NAMESPACE_DNS),
+            UUID::v5("name", UUID::NAMESPACE_DNS),
+        ] {
+            assert_eq!(uuid.fingerprint_generator(), GeneratorHint::NameBased);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_audit_malformed() {
+        let mut bytes = [0x5a; 16];
+        bytes[6] = 0xf5;
+        bytes[8] = 0xc5;
+        assert_eq!(
+            UUID(bytes).audit(),
+            [Anomaly::UnknownVersion, Anomaly::NonRfcVariant]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_audit_entropy() {
+        let mut bytes = [0u8; 16];
+        bytes[6] = 0x40;
+        bytes[8] = 0x80;
+        assert_eq!(UUID(bytes).audit(), [Anomaly::SuspiciousEntropy]);
+
+        let mut bytes = [0x5a; 16];
+        bytes[6] = 0x4a;
+        bytes[8] = 0x9a;
+        assert_eq!(UUID(bytes).audit(), []);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_audit_future_timestamp() {
+        let mut bytes = [0xff; 16];
+        bytes[6] = 0x7f;
+        bytes[8] = 0xbf;
+        assert_eq!(UUID(bytes).audit(), [Anomaly::FutureTimestamp]);
+
+        let mut bytes = [0xff; 16];
+        bytes[6] = 0x1f;
+        bytes[8] = 0xbf;
+        assert_eq!(UUID(bytes).audit(), [Anomaly::FutureTimestamp]);
+    }
+}
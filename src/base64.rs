@@ -0,0 +1,82 @@
+//! URL-safe base64 without padding, 22 characters over the 16 bytes, for
+//! JWT claims and query strings.
+
+#![cfg(feature = "base64")]
+
+use alloc::string::String;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, DecodeError, Engine};
+
+use crate::{Error, UUID};
+
+/// Length of the unpadded base64 form of 16 bytes.
+const BASE64_LEN: usize = 22;
+
+impl UUID {
+    /// Returns the 22-character URL-safe base64 form without padding.
+    pub fn to_base64(&self) -> String {
+        URL_SAFE_NO_PAD.encode(self.0)
+    }
+
+    /// Parse the 22-character URL-safe base64 form produced by [`UUID::to_base64`].
+    pub fn from_base64(s: &str) -> Result<UUID, Error> {
+        if s.len() != BASE64_LEN {
+            return Err(Error::InvalidLength(s.len()));
+        }
+
+        let mut bytes = [0u8; 16];
+        match URL_SAFE_NO_PAD.decode_slice(s, &mut bytes) {
+            Ok(16) => Ok(UUID(bytes)),
+            Ok(n) => Err(Error::InvalidLength(n)),
+            Err(base64::DecodeSliceError::DecodeError(
+                DecodeError::InvalidByte(i, _) | DecodeError::InvalidLastSymbol(i, _),
+            )) => Err(Error::InvalidCharacter(i)),
+            Err(_) => Err(Error::InvalidLength(s.len())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        assert_eq!(UUID::NAMESPACE_DNS.to_base64(), "a6e4EJ2tEdGAtADAT9QwyA");
+        assert_eq!(UUID::max().to_base64(), "_____________________w");
+
+        let uuids = [UUID::NAMESPACE_DNS, UUID::nil(), UUID::max()].into_iter();
+        #[cfg(feature = "rand")]
+        let uuids = uuids.chain((0..256).map(|_| UUID::v4().to_uuid()));
+        for uuid in uuids {
+            let s = uuid.to_base64();
+            assert_eq!(s.len(), BASE64_LEN);
+            assert_eq!(UUID::from_base64(&s), Ok(uuid));
+        }
+    }
+
+    #[test]
+    fn test_from_base64_invalid() {
+        assert_eq!(
+            UUID::from_base64("a6e4EJ2tEdGAtADAT9Qwy"),
+            Err(Error::InvalidLength(21))
+        );
+        assert_eq!(
+            UUID::from_base64("a6e4EJ2tEdGAtADAT9QwyA=="),
+            Err(Error::InvalidLength(24))
+        );
+        assert_eq!(
+            UUID::from_base64("a6e4EJ2tEdGAtADAT9QwyAAA"),
+            Err(Error::InvalidLength(24))
+        );
+        assert_eq!(
+            UUID::from_base64("a6e4EJ2tEdGAtADAT9Qwy+"),
+            Err(Error::InvalidCharacter(21))
+        );
+        // The last character carries four unused bits which must be zero.
+        assert_eq!(
+            UUID::from_base64("a6e4EJ2tEdGAtADAT9QwyB"),
+            Err(Error::InvalidCharacter(21))
+        );
+    }
+}
@@ -0,0 +1,204 @@
+//! Assemble a UUID from its parts without handling the version and variant
+//! multiplexing by hand.
+
+use crate::{Layout, Timestamp, Variant, Version, UUID};
+
+/// Builds a UUID field by field, the timestamp is laid out as in version 1.
+#[derive(Debug, Default)]
+pub struct Builder {
+    timestamp: u64,
+    clock_seq: u16,
+    node: [u8; 6],
+    version: Option<Version>,
+    variant: Option<Variant>,
+}
+
+impl Builder {
+    /// Start from the nil UUID.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the version stamped into the high timestamp field.
+    pub fn with_version(mut self, version: Version) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Set the variant stamped into the high clock sequence byte.
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// Set the 60-bit count of 100-ns intervals since the UTC epoch,
+    /// higher bits are dropped.
+    pub fn with_timestamp(mut self, timestamp: u64) -> Self {
+        self.timestamp = timestamp & 0x0fff_ffff_ffff_ffff;
+        self
+    }
+
+    /// Set the clock sequence, its leading bits are overwritten by the variant.
+    pub fn with_clock_seq(mut self, clock_seq: u16) -> Self {
+        self.clock_seq = clock_seq;
+        self
+    }
+
+    /// Set the IEEE 802 node identifier.
+    pub fn with_node(mut self, node: [u8; 6]) -> Self {
+        self.node = node;
+        self
+    }
+
+    /// Assemble the UUID.
+    pub fn build(self) -> UUID {
+        let version = self.version.map_or(0, |v| v as u16);
+        let clock_seq_high = (self.clock_seq >> 8) as u8;
+
+        Layout {
+            field_low: self.timestamp as u32,
+            field_mid: (self.timestamp >> 32) as u16,
+            field_high_and_version: (self.timestamp >> 48) as u16 & 0xfff | version << 12,
+            clock_seq_high_and_reserved: match self.variant {
+                Some(variant) => variant.stamp(clock_seq_high),
+                None => clock_seq_high,
+            },
+            clock_seq_low: self.clock_seq as u8,
+            node: self.node,
+        }
+        .to_uuid()
+    }
+}
+
+impl UUID {
+    /// Generate a time based UUID (version 1) from fully specified inputs,
+    /// without touching the random generator or the global timestamp state.
+    pub fn v1_exact(timestamp: Timestamp, clock_seq: u16, node: [u8; 6]) -> Layout {
+        Layout {
+            field_low: (&timestamp & 0xffff_ffff) as u32,
+            field_mid: ((&timestamp >> 32) & 0xffff) as u16,
+            field_high_and_version: ((&timestamp >> 48) & 0xfff) as u16
+                | (Version::TIME as u16) << 12,
+            clock_seq_high_and_reserved: Variant::RFC.stamp((clock_seq >> 8) as u8),
+            clock_seq_low: (clock_seq & 0xff) as u8,
+            node,
+        }
+    }
+
+    /// Build a time based UUID (version 1) from pinned inputs only, without
+    /// reading the clock, the MAC address or the random generator. The same
+    /// as [`UUID::v1_exact`] with the arguments in another order.
+    pub fn new_v1_at(ticks: u64, node: [u8; 6], clock_seq: u16) -> Layout {
+        Self::v1_exact(Timestamp(ticks), clock_seq, node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_v1() {
+        let uuid = Builder::new()
+            .with_version(Version::TIME)
+            .with_variant(Variant::RFC)
+            .with_timestamp(0x01d1_9dad_6ba7_b810)
+            .with_clock_seq(0x00b4)
+            .with_node([0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8])
+            .build();
+
+        assert_eq!(uuid, UUID::NAMESPACE_DNS);
+        let layout = uuid.decode();
+        assert_eq!(layout.get_version(), Some(Version::TIME));
+        assert_eq!(layout.get_variant(), Some(Variant::RFC));
+    }
+
+    #[test]
+    fn test_new_v1_at() {
+        let uuid = UUID::new_v1_at(
+            0x01d3_bfde_63b0_0000,
+            [0x03, 0x2a, 0x35, 0x0d, 0x13, 0x80],
+            0x0abc,
+        );
+        assert_eq!(uuid.to_string(), "63b00000-bfde-11d3-8abc-032a350d1380");
+        assert_eq!(
+            UUID::new_v1_at(
+                0x01d1_9dad_6ba7_b810,
+                [0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8],
+                0x00b4
+            )
+            .to_uuid(),
+            UUID::NAMESPACE_DNS
+        );
+
+        assert_eq!(
+            uuid,
+            UUID::v1_exact(
+                Timestamp(0x01d3_bfde_63b0_0000),
+                0x0abc,
+                [0x03, 0x2a, 0x35, 0x0d, 0x13, 0x80]
+            )
+        );
+    }
+
+    #[test]
+    fn test_v1_exact() {
+        let node = [0x03, 0x2a, 0x35, 0x0d, 0x13, 0x80];
+        let uuid = UUID::v1_exact(Timestamp(0x01d3_bfde_63b0_0000), 0x0abc, node);
+        let (low, mid, high, clock_seq, _) = uuid.as_fields();
+
+        assert_eq!(uuid.get_version(), Some(Version::TIME));
+        assert_eq!(uuid.get_variant(), Some(Variant::RFC));
+        assert_eq!(
+            ((high & 0xfff) as u64) << 48 | (mid as u64) << 32 | low as u64,
+            0x01d3_bfde_63b0_0000
+        );
+        assert_eq!(clock_seq & 0x3fff, 0x0abc);
+        assert_eq!(uuid.get_mac().0, node);
+        assert_eq!(
+            uuid,
+            UUID::v1_exact(Timestamp(0x01d3_bfde_63b0_0000), 0x0abc, node)
+        );
+    }
+
+    #[test]
+    fn test_new_v1_at_matches_v1_exact() {
+        for (ticks, node, clock_seq) in [
+            (0, [0; 6], 0),
+            (
+                0x01d1_9dad_6ba7_b810,
+                [0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8],
+                0x00b4,
+            ),
+            (0x0fff_ffff_ffff_ffff, [0xff; 6], 0x3fff),
+            (u64::MAX, [0x03, 0x2a, 0x35, 0x0d, 0x13, 0x80], 0xffff),
+        ] {
+            let uuid = UUID::new_v1_at(ticks, node, clock_seq);
+            assert_eq!(uuid, UUID::v1_exact(Timestamp(ticks), clock_seq, node));
+            assert_eq!(
+                uuid.to_uuid(),
+                Builder::new()
+                    .with_version(Version::TIME)
+                    .with_variant(Variant::RFC)
+                    .with_timestamp(ticks)
+                    .with_clock_seq(clock_seq)
+                    .with_node(node)
+                    .build()
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_default() {
+        assert_eq!(Builder::new().build(), UUID::nil());
+        assert_eq!(
+            Builder::new()
+                .with_clock_seq(0xffff)
+                .with_variant(Variant::NCS)
+                .build()
+                .decode()
+                .clock_seq_high_and_reserved,
+            0x7f
+        );
+    }
+}
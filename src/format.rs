@@ -0,0 +1,370 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+
+use crate::{Error, UUID};
+
+/// The two hex digits of every byte value, built at compile time.
+const HEX_LOWER: [[u8; 2]; 256] = hex_table(b"0123456789abcdef");
+const HEX_UPPER: [[u8; 2]; 256] = hex_table(b"0123456789ABCDEF");
+
+const fn hex_table(digits: &[u8; 16]) -> [[u8; 2]; 256] {
+    let mut table = [[0u8; 2]; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = [digits[i >> 4], digits[i & 0xf]];
+        i += 1;
+    }
+    table
+}
+
+/// Length of the ASCII85 form, five characters for each four bytes.
+const ASCII85_LEN: usize = 20;
+
+/// Length of the Crockford base32 form, 26 five-bit digits covering 128 bits.
+const BASE32_LEN: usize = 26;
+
+/// The Crockford base32 alphabet, without `I`, `L`, `O` and `U`.
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+#[cfg(feature = "alloc")]
+impl UUID {
+    /// Returns the 32 lowercase hex digits without hyphens.
+    pub fn to_simple_string(&self) -> String {
+        format!("{:032x}", self.as_u128())
+    }
+
+    /// Returns a fixed-width string whose lexicographic order matches the
+    /// order of UUIDs: `a < b` if and only if
+    /// `a.to_sortable_string() < b.to_sortable_string()`.
+    pub fn to_sortable_string(&self) -> String {
+        // Fixed-width lowercase hex of the big-endian bytes, where ASCII digits
+        // sort before ASCII lowercase letters just like their nibble values.
+        self.to_simple_string()
+    }
+
+    /// Returns the lowercase hyphenated form prefixed with `urn:uuid:`, the
+    /// exact form accepted back by [`UUID::parse_str`].
+    pub fn to_urn_string(&self) -> String {
+        format!("urn:uuid:{}", self)
+    }
+
+    /// Former name of [`UUID::to_urn_string`].
+    #[deprecated(note = "use `UUID::to_urn_string`")]
+    pub fn to_urn(&self) -> String {
+        self.to_urn_string()
+    }
+
+    /// Returns the hyphenated form wrapped in braces, as used for Microsoft GUIDs.
+    pub fn to_braced_string(&self) -> String {
+        format!("{{{}}}", self)
+    }
+
+    /// Returns the 20-character ASCII85 form, using the Adobe alphabet `!`
+    /// through `u` without the `z` abbreviation for zero groups.
+    pub fn to_ascii85(&self) -> String {
+        let mut out = String::with_capacity(ASCII85_LEN);
+        for group in self.0.chunks_exact(4) {
+            let mut n = u32::from_be_bytes([group[0], group[1], group[2], group[3]]);
+            let mut digits = [0u8; 5];
+            for digit in digits.iter_mut().rev() {
+                *digit = b'!' + (n % 85) as u8;
+                n /= 85;
+            }
+            out.extend(digits.iter().map(|&d| d as char));
+        }
+        out
+    }
+
+    /// Returns the 26-character Crockford base32 form in upper case.
+    pub fn to_base32(&self) -> String {
+        let n = self.as_u128();
+        (0..BASE32_LEN)
+            .rev()
+            .map(|i| CROCKFORD[(n >> (i * 5)) as usize & 0x1f] as char)
+            .collect()
+    }
+}
+
+impl UUID {
+    /// Write the lowercase hyphenated form into `buf` without allocating,
+    /// returning a view of it.
+    pub fn encode_lower<'a>(&self, buf: &'a mut [u8; 36]) -> &'a str {
+        self.encode(buf, &HEX_LOWER)
+    }
+
+    /// Write the upper case hyphenated form into `buf` without allocating,
+    /// returning a view of it.
+    pub fn encode_upper<'a>(&self, buf: &'a mut [u8; 36]) -> &'a str {
+        self.encode(buf, &HEX_UPPER)
+    }
+
+    fn encode<'a>(&self, buf: &'a mut [u8; 36], table: &[[u8; 2]; 256]) -> &'a str {
+        let mut i = 0;
+        for (n, &b) in self.0.iter().enumerate() {
+            if matches!(n, 4 | 6 | 8 | 10) {
+                buf[i] = b'-';
+                i += 1;
+            }
+            buf[i..i + 2].copy_from_slice(&table[b as usize]);
+            i += 2;
+        }
+        core::str::from_utf8(buf).expect("hex digits and hyphens are ASCII")
+    }
+
+    /// Parse the 26-character Crockford base32 form, case-insensitively and
+    /// reading `I`/`L` as `1` and `O` as `0`.
+    pub fn from_base32(s: &str) -> Result<UUID, Error> {
+        let src = s.as_bytes();
+        if src.len() != BASE32_LEN {
+            return Err(Error::InvalidLength(src.len()));
+        }
+        // 26 digits hold 130 bits, the first one may only use the low three.
+        if !(b'0'..=b'7').contains(&src[0]) {
+            return Err(Error::InvalidCharacter(0));
+        }
+
+        let mut n: u128 = 0;
+        for (i, &c) in src.iter().enumerate() {
+            let digit = match c.to_ascii_uppercase() {
+                b'O' => 0,
+                b'I' | b'L' => 1,
+                c => CROCKFORD
+                    .iter()
+                    .position(|&d| d == c)
+                    .ok_or(Error::InvalidCharacter(i))?,
+            };
+            n = n << 5 | digit as u128;
+        }
+        Ok(UUID::from_u128(n))
+    }
+
+    /// Parse the 20-character ASCII85 form produced by [`UUID::to_ascii85`].
+    pub fn from_ascii85(s: &str) -> Result<UUID, Error> {
+        let src = s.as_bytes();
+        if src.len() != ASCII85_LEN {
+            return Err(Error::InvalidLength(src.len()));
+        }
+
+        let mut bytes = [0u8; 16];
+        for (i, group) in src.chunks_exact(5).enumerate() {
+            let mut n: u64 = 0;
+            for (j, &c) in group.iter().enumerate() {
+                if !(b'!'..=b'u').contains(&c) {
+                    return Err(Error::InvalidCharacter(i * 5 + j));
+                }
+                n = n * 85 + (c - b'!') as u64;
+            }
+            // Five digits reach past 32 bits, `s8W-!` is the largest group.
+            let n = u32::try_from(n).map_err(|_| Error::InvalidCharacter(i * 5))?;
+            bytes[i * 4..i * 4 + 4].copy_from_slice(&n.to_be_bytes());
+        }
+        Ok(UUID(bytes))
+    }
+}
+
+/// The 32 hex digits without hyphens, `{:#x}` prepends `0x`.
+impl fmt::LowerHex for UUID {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if fmt.alternate() {
+            fmt.write_str("0x")?;
+        }
+        write!(fmt, "{:032x}", self.as_u128())
+    }
+}
+
+/// The 32 upper case hex digits without hyphens, `{:#X}` prepends `0x`.
+impl fmt::UpperHex for UUID {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if fmt.alternate() {
+            fmt.write_str("0x")?;
+        }
+        write!(fmt, "{:032X}", self.as_u128())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_sortable_string() {
+        let mut uuids: Vec<UUID> = (0..=255u128)
+            .map(|i| UUID::from_u128(i.wrapping_mul(0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c835)))
+            .collect();
+        #[cfg(feature = "rand")]
+        uuids.extend((0..256).map(|_| UUID::v4().to_uuid()));
+        uuids.sort();
+
+        for pair in uuids.windows(2) {
+            assert_eq!(
+                pair[0].cmp(&pair[1]),
+                pair[0]
+                    .to_sortable_string()
+                    .cmp(&pair[1].to_sortable_string())
+            );
+        }
+    }
+
+    #[test]
+    fn test_display_table() {
+        let values = (0..=255u128)
+            .map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c835))
+            .chain([0, u128::MAX, UUID::NAMESPACE_DNS.as_u128()]);
+        for v in values {
+            let b = UUID::from_u128(v).0;
+            let expected = format!(
+                "{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+                b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7], b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15],
+            );
+            assert_eq!(UUID(b).to_string(), expected);
+            assert_eq!(UUID(b).encode_upper(&mut [0; 36]), expected.to_uppercase());
+        }
+        for (byte, pair) in HEX_LOWER.iter().enumerate() {
+            assert_eq!(pair, format!("{:02x}", byte).as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_encode() {
+        let uuids = [UUID::NAMESPACE_DNS, UUID::nil(), UUID::max()].into_iter();
+        #[cfg(feature = "rand")]
+        let uuids = uuids.chain((0..16).map(|_| UUID::v4().to_uuid()));
+        for uuid in uuids {
+            let mut buf = [0u8; 36];
+            assert_eq!(uuid.encode_lower(&mut buf), format!("{}", uuid));
+            assert_eq!(
+                uuid.encode_upper(&mut buf),
+                format!("{}", uuid).to_uppercase()
+            );
+        }
+        assert_eq!(
+            UUID::NAMESPACE_DNS.encode_upper(&mut [0; 36]),
+            "6BA7B810-9DAD-11D1-80B4-00C04FD430C8"
+        );
+    }
+
+    #[test]
+    fn test_hex() {
+        let uuid = UUID::NAMESPACE_DNS;
+        assert_eq!(format!("{:x}", uuid), "6ba7b8109dad11d180b400c04fd430c8");
+        assert_eq!(format!("{:X}", uuid), "6BA7B8109DAD11D180B400C04FD430C8");
+        assert_eq!(format!("{:#x}", uuid), "0x6ba7b8109dad11d180b400c04fd430c8");
+        assert_eq!(format!("{:#X}", uuid), "0x6BA7B8109DAD11D180B400C04FD430C8");
+        assert_eq!(format!("{:x}", UUID::nil()), "0".repeat(32));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_string_forms() {
+        let uuid = UUID::NAMESPACE_DNS;
+        assert_eq!(uuid.to_simple_string(), "6ba7b8109dad11d180b400c04fd430c8");
+        assert_eq!(
+            uuid.to_urn_string(),
+            "urn:uuid:6ba7b810-9dad-11d1-80b4-00c04fd430c8"
+        );
+        assert_eq!(
+            uuid.to_braced_string(),
+            "{6ba7b810-9dad-11d1-80b4-00c04fd430c8}"
+        );
+        assert_eq!(
+            UUID::nil().to_simple_string(),
+            "00000000000000000000000000000000"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_urn_round_trip() {
+        let uuids = [UUID::NAMESPACE_DNS, UUID::nil(), UUID::max()].into_iter();
+        #[cfg(feature = "rand")]
+        let uuids = uuids.chain((0..16).map(|_| UUID::v4().to_uuid()));
+        for uuid in uuids {
+            let urn = uuid.to_urn_string();
+            assert_eq!(urn, format!("urn:uuid:{}", uuid));
+            assert_eq!(urn.parse::<UUID>(), Ok(uuid));
+            assert!(!uuid.to_string().starts_with("urn:"));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_base32() {
+        assert_eq!(
+            UUID::NAMESPACE_DNS.to_base32(),
+            "3BMYW117DD278R1D00R17X8C68"
+        );
+        assert_eq!(UUID::nil().to_base32(), "00000000000000000000000000");
+        assert_eq!(UUID::max().to_base32(), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+
+        let uuids = [UUID::NAMESPACE_DNS, UUID::nil(), UUID::max()].into_iter();
+        #[cfg(feature = "rand")]
+        let uuids = uuids.chain((0..256).map(|_| UUID::v4().to_uuid()));
+        for uuid in uuids {
+            let s = uuid.to_base32();
+            assert_eq!(UUID::from_base32(&s), Ok(uuid));
+            assert_eq!(UUID::from_base32(&s.to_lowercase()), Ok(uuid));
+        }
+    }
+
+    #[test]
+    fn test_from_base32_invalid() {
+        assert_eq!(
+            UUID::from_base32("3bmywlI7dd278r1door17x8c68"),
+            UUID::from_base32("3BMYW117DD278R1D00R17X8C68")
+        );
+        assert_eq!(
+            UUID::from_base32("3BMYW117DD278R1D00R17X8C6"),
+            Err(Error::InvalidLength(25))
+        );
+        assert_eq!(
+            UUID::from_base32("3BMYW117DD278R1D00R17X8C6U"),
+            Err(Error::InvalidCharacter(25))
+        );
+        assert_eq!(
+            UUID::from_base32("3BMYW117DD-78R1D00R17X8C68"),
+            Err(Error::InvalidCharacter(10))
+        );
+        assert_eq!(
+            UUID::from_base32("8ZZZZZZZZZZZZZZZZZZZZZZZZZ"),
+            Err(Error::InvalidCharacter(0))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_ascii85() {
+        assert_eq!(UUID::NAMESPACE_DNS.to_ascii85(), "CT\"ZRSZNLOJ@#<l:Xh)>");
+        assert_eq!(UUID::nil().to_ascii85(), "!!!!!!!!!!!!!!!!!!!!");
+        assert_eq!(UUID::max().to_ascii85(), "s8W-!s8W-!s8W-!s8W-!");
+
+        let uuids = [UUID::NAMESPACE_DNS, UUID::nil(), UUID::max()].into_iter();
+        #[cfg(feature = "rand")]
+        let uuids = uuids.chain((0..256).map(|_| UUID::v4().to_uuid()));
+        for uuid in uuids {
+            assert_eq!(UUID::from_ascii85(&uuid.to_ascii85()), Ok(uuid));
+        }
+    }
+
+    #[test]
+    fn test_from_ascii85_invalid() {
+        assert_eq!(
+            UUID::from_ascii85("CT\"ZRSZNLOJ@#<l:Xh)>"),
+            Ok(UUID::NAMESPACE_DNS)
+        );
+        assert_eq!(
+            UUID::from_ascii85("!!!!!!!!!!!!!!!!!!!"),
+            Err(Error::InvalidLength(19))
+        );
+        assert_eq!(
+            UUID::from_ascii85("!!!!!!!!!!z!!!!!!!!!"),
+            Err(Error::InvalidCharacter(10))
+        );
+        assert_eq!(
+            UUID::from_ascii85("!!!!!s8W-\"!!!!!!!!!!"),
+            Err(Error::InvalidCharacter(5))
+        );
+    }
+}
@@ -2,23 +2,105 @@
 //! [RFC 4122](https://tools.ietf.org/html/rfc4122). UUIDs are 128-bit numbers
 //! that can be used to uniquely identify resources in distributed systems. Multiple
 //! UUID versions are supported, including timestamp-based (v1), DCE Security (v2),
-//! MD5 hash-based (v3), random (v4) and SHA-1 hash-based (v5) variants.
+//! MD5 hash-based (v3), random (v4), SHA-1 hash-based (v5) and the
+//! [RFC 9562](https://www.rfc-editor.org/rfc/rfc9562) reordered time-based (v6),
+//! time-ordered (v7) and custom (v8) variants.
+
+//!
+//! Without the default `std` feature the crate is `no_std`: the `UUID` type,
+//! byte conversions, parsing, version/variant decoding and `Display` stay
+//! available, the `alloc` feature adds the `String` and `Vec` returning APIs.
+//!
+//! ```
+//! # #![no_std]
+//! # extern crate std;
+//! use uuid_rs::{Version, UUID};
+//!
+//! # fn main() {
+//! let uuid: UUID = "6ba7b810-9dad-11d1-80b4-00c04fd430c8".parse().unwrap();
+//! assert_eq!(uuid.decode().get_version(), Some(Version::TIME));
+//! assert_eq!(uuid.as_u128(), 0x6ba7b810_9dad_11d1_80b4_00c04fd430c8);
+//! # }
+//! ```
 
 #![doc(html_root_url = "https://docs.rs/uuid-rs")]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+pub mod arbitrary;
+pub mod audit;
+pub mod base64;
+pub mod builder;
+pub mod format;
+pub mod mnemonic;
 pub mod name;
+pub mod parse;
+pub mod pseudonym;
 pub mod rand;
+pub mod serde;
+pub mod serde_tagged;
 pub mod time;
+pub mod typed;
+pub mod uuid_compat;
+pub mod zeroize;
 
 use core::fmt;
 use core::sync::atomic;
-use std::time::SystemTime;
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime};
+
+pub use audit::{Anomaly, GeneratorHint, SourceGuess};
+pub use builder::Builder;
+#[cfg(feature = "hsha1")]
+pub use name::NameGenerator;
+pub use parse::ParseMode;
+#[cfg(feature = "rand")]
+pub use rand::UuidStream;
+#[cfg(feature = "rand")]
+pub use time::Generator;
+#[cfg(feature = "zeroize")]
+pub use zeroize::SecretUUID;
 
 /// Is 100-ns ticks between UNIX and UTC epochs.
 pub const UTC_EPOCH: u64 = 0x01B2_1DD2_1381_4000;
 
+/// Mask of the 60 timestamp bits a UUID can hold.
+pub(crate) const TICKS_MASK: u64 = 0x0fff_ffff_ffff_ffff;
+
+/// Convert a Unix time to 100-ns ticks since the UTC epoch, saturating at
+/// the largest 60-bit timestamp.
+pub fn unix_to_utc_ticks(secs: u64, nanos: u32) -> u64 {
+    let intervals = secs as u128 * 10_000_000 + nanos as u128 / 100;
+    (intervals + UTC_EPOCH as u128).min(TICKS_MASK as u128) as u64
+}
+
+/// Convert 60-bit ticks since the UTC epoch back to Unix seconds and nanoseconds.
+pub fn utc_ticks_to_unix(ticks: u64) -> (u64, u32) {
+    let intervals = (ticks & TICKS_MASK).wrapping_sub(UTC_EPOCH) & TICKS_MASK;
+    (
+        intervals / 10_000_000,
+        (intervals % 10_000_000) as u32 * 100,
+    )
+}
+
+/// CRC-32 (IEEE 802.3) checksum.
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &b in data {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
 /// The UUID format is 16 octets.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub struct Layout {
     /// The low field of the Timestamp.
     pub field_low: u32,
@@ -51,8 +133,27 @@ impl Layout {
         )
     }
 
-    /// Returns a byte slice of this UUID content.
+    /// Like [`Layout::as_fields`], but the first three fields byte-swapped
+    /// as in a Microsoft GUID, e.g. for .NET's `Guid(int, short, short, ...)`.
+    pub fn as_fields_le(&self) -> (u32, u16, u16, u16, u64) {
+        let (low, mid, high, clock_seq, node) = self.as_fields();
+        (
+            low.swap_bytes(),
+            mid.swap_bytes(),
+            high.swap_bytes(),
+            clock_seq,
+            node,
+        )
+    }
+
+    /// Former name of [`Layout::to_uuid`], it never returned a byte slice.
+    #[deprecated(note = "use `Layout::to_uuid` or `UUID::from`")]
     pub fn as_bytes(&self) -> UUID {
+        self.to_uuid()
+    }
+
+    /// Assemble the fields back into a UUID.
+    pub fn to_uuid(&self) -> UUID {
         UUID([
             self.field_low.to_be_bytes()[0],
             self.field_low.to_be_bytes()[1],
@@ -73,54 +174,182 @@ impl Layout {
         ])
     }
 
-    /// Get the version of the current generated UUID.
+    /// Get the version of the current generated UUID, `None` for a version
+    /// number this crate doesn't know, see [`Layout::get_version_num`].
     pub fn get_version(&self) -> Option<Version> {
-        match (self.field_high_and_version >> 12) & 0xf {
-            0x01 => Some(Version::TIME),
-            0x02 => Some(Version::DCE),
-            0x03 => Some(Version::MD5),
-            0x04 => Some(Version::RAND),
-            0x05 => Some(Version::SHA1),
-            _ => None,
-        }
+        Version::from_u8(self.get_version_num())
+    }
+
+    /// Get the raw version number from 0 to 15, known to this crate or not.
+    pub fn get_version_num(&self) -> u8 {
+        (self.field_high_and_version >> 12) as u8
     }
 
     /// Get the variant field of the current generated UUID.
     pub fn get_variant(&self) -> Option<Variant> {
-        match (self.clock_seq_high_and_reserved >> 4) & 0xf {
-            0x00 => Some(Variant::NCS),
-            0x01 => Some(Variant::RFC),
-            0x02 => Some(Variant::MS),
-            0x03 => Some(Variant::FUT),
+        Variant::from_u8(self.clock_seq_high_and_reserved >> 5)
+    }
+
+    /// Overwrite the version nibble, leaving the timestamp bits alone.
+    pub fn set_version(&mut self, version: Version) {
+        self.field_high_and_version = self.field_high_and_version & 0xfff | (version as u16) << 12;
+    }
+
+    /// Overwrite the leading variant bits, leaving the clock sequence bits alone.
+    pub fn set_variant(&mut self, variant: Variant) {
+        self.clock_seq_high_and_reserved = variant.stamp(self.clock_seq_high_and_reserved);
+    }
+
+    /// Get timestamp where UUID generated in, as 100-ns ticks since the Unix epoch.
+    /// Returns `None` for versions without a 100-ns timestamp or one before the Unix epoch.
+    pub fn get_time(&self) -> Option<u64> {
+        match self.get_timestamp()? {
+            UuidTimestamp {
+                value,
+                unit: TimeUnit::Ticks,
+                ..
+            } => value.checked_sub(UTC_EPOCH),
             _ => None,
         }
     }
 
-    /// Get timestamp where UUID generated in.
-    pub fn get_time(&self) -> u64 {
-        let t = ((self.field_high_and_version) as u64) << 48
-            | (self.field_mid as u64) << 32
-            | self.field_low as u64;
+    /// Get the embedded timestamp as stored, with its unit and the counter
+    /// next to it: the clock sequence for versions 1, 2 and 6, the 12 bits
+    /// after the milliseconds for version 7. Returns `None` for versions
+    /// without a timestamp.
+    pub fn get_timestamp(&self) -> Option<UuidTimestamp> {
+        let (low, mid, high) = (
+            self.field_low as u64,
+            self.field_mid as u64,
+            (self.field_high_and_version & 0xfff) as u64,
+        );
+        let (value, unit, counter) = match self.get_version()? {
+            Version::TIME => (
+                high << 48 | mid << 32 | low,
+                TimeUnit::Ticks,
+                self.get_clock_seq(),
+            ),
+            // The low byte of the clock sequence carries the domain.
+            Version::DCE => (
+                high << 48 | mid << 32 | low,
+                TimeUnit::Ticks,
+                (self.clock_seq_high_and_reserved & 0x3f) as u16,
+            ),
+            Version::REORDERED => (
+                low << 28 | mid << 12 | high,
+                TimeUnit::Ticks,
+                self.get_clock_seq(),
+            ),
+            Version::UNIX => (low << 16 | mid, TimeUnit::Millis, high as u16),
+            _ => return None,
+        };
+
+        Some(UuidTimestamp {
+            value,
+            unit,
+            counter,
+        })
+    }
+
+    /// Like [`Layout::get_time`], but a timestamp before the Unix epoch is an
+    /// `Error::Underflow` instead of `None`. Returns `Ok(None)` for versions
+    /// without a 100-ns timestamp.
+    pub fn try_get_time(&self) -> Result<Option<u64>, Error> {
+        match self.get_version() {
+            Some(Version::TIME | Version::DCE | Version::REORDERED) => {
+                self.get_time().map(Some).ok_or(Error::Underflow)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Get the wall-clock time where UUID generated in, for versions 1, 2, 6 and 7.
+    #[cfg(feature = "std")]
+    pub fn get_datetime(&self) -> Option<SystemTime> {
+        if self.get_version()? == Version::UNIX {
+            let millis = self.to_uuid().unix_millis()?;
+            return SystemTime::UNIX_EPOCH.checked_add(Duration::from_millis(millis));
+        }
+        let ticks = self.get_time()?;
+        SystemTime::UNIX_EPOCH.checked_add(Duration::from_nanos(ticks.checked_mul(100)?))
+    }
+
+    /// Get the security domain of a version-2 UUID, carried in `clock_seq_low`.
+    pub fn get_domain(&self) -> Option<Domain> {
+        if self.get_version()? != Version::DCE {
+            return None;
+        }
+        Domain::from_u8(self.clock_seq_low)
+    }
+
+    /// Get the POSIX UID or GID of a version-2 UUID, carried in `field_low`.
+    pub fn get_local_id(&self) -> Option<u32> {
+        if self.get_version()? != Version::DCE {
+            return None;
+        }
+        Some(self.field_low)
+    }
 
-        t.checked_sub(UTC_EPOCH).unwrap()
+    /// Get the 14-bit clock sequence, without the variant bits.
+    pub fn get_clock_seq(&self) -> u16 {
+        ((self.clock_seq_high_and_reserved & 0x3f) as u16) << 8 | self.clock_seq_low as u16
     }
 
     /// Get the MAC-address where UUID generated with.
     pub fn get_mac(&self) -> Node {
         Node(self.node)
     }
+
+    /// Tell a real MAC address from a random node by the multicast bit.
+    pub fn node_kind(&self) -> NodeKind {
+        if self.get_mac().is_multicast() {
+            NodeKind::Random
+        } else {
+            NodeKind::Real
+        }
+    }
+}
+
+/// Where the node of a time based UUID came from.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NodeKind {
+    /// The MAC address of a network interface.
+    Real,
+    /// A random or hashed node, marked by the multicast bit.
+    Random,
 }
 
 /// Domain is security-domain-relative name.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Domain {
     PERSON = 0,
     GROUP,
     ORG,
 }
 
+impl Domain {
+    /// Map a raw domain byte to a domain, returns `None` for unknown values.
+    pub fn from_u8(value: u8) -> Option<Domain> {
+        match value {
+            0 => Some(Domain::PERSON),
+            1 => Some(Domain::GROUP),
+            2 => Some(Domain::ORG),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<u8> for Domain {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Domain::from_u8(value).ok_or(Error::InvalidDomain(value))
+    }
+}
+
 /// Variant is a type field determines the layout of the UUID.
 #[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum Variant {
     /// Reserved, NCS backward compatibility.
     NCS = 0,
@@ -132,8 +361,35 @@ pub enum Variant {
     FUT,
 }
 
+impl Variant {
+    /// Map the three most significant bits of the high clock sequence byte
+    /// to a variant, returns `None` for values above `0b111`.
+    pub fn from_u8(bits: u8) -> Option<Variant> {
+        match bits {
+            0b000..=0b011 => Some(Variant::NCS),
+            0b100 | 0b101 => Some(Variant::RFC),
+            0b110 => Some(Variant::MS),
+            0b111 => Some(Variant::FUT),
+            _ => None,
+        }
+    }
+
+    /// Overwrite the leading variant bits of the high clock sequence byte.
+    pub(crate) fn stamp(self, clock_seq_high: u8) -> u8 {
+        match self {
+            Variant::NCS => clock_seq_high & 0x7f,
+            Variant::RFC => clock_seq_high & 0x3f | 0x80,
+            Variant::MS => clock_seq_high & 0x1f | 0xc0,
+            Variant::FUT => clock_seq_high & 0x1f | 0xe0,
+        }
+    }
+}
+
 /// Version represents the type of UUID, and is in the most significant 4 bits of the Timestamp.
+/// More versions may be added, an unknown one is still readable as a number
+/// with [`Layout::get_version_num`].
 #[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum Version {
     /// The time-based version specified in this document.
     TIME = 1,
@@ -145,6 +401,60 @@ pub enum Version {
     RAND,
     /// The name-based version specified in rfc4122 document that uses SHA-1 hashing.
     SHA1,
+    /// The time-based version specified in rfc9562 document, with the timestamp reordered for sorting.
+    REORDERED,
+    /// The time-ordered version specified in rfc9562 document that uses a Unix timestamp.
+    UNIX = 7,
+    /// The custom version specified in rfc9562 document for application-specific data.
+    CUSTOM,
+}
+
+impl Version {
+    /// Map a version nibble to a version, returns `None` for unknown values.
+    pub fn from_u8(nibble: u8) -> Option<Version> {
+        match nibble {
+            0x01 => Some(Version::TIME),
+            0x02 => Some(Version::DCE),
+            0x03 => Some(Version::MD5),
+            0x04 => Some(Version::RAND),
+            0x05 => Some(Version::SHA1),
+            0x06 => Some(Version::REORDERED),
+            0x07 => Some(Version::UNIX),
+            0x08 => Some(Version::CUSTOM),
+            _ => None,
+        }
+    }
+}
+
+/// Byte order a UUID may be stored in by external systems.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Endianness {
+    /// The canonical big-endian order specified in rfc4122 document.
+    Big,
+    /// Microsoft GUID order, the first three fields little-endian.
+    Mixed,
+    /// All 16 bytes reversed, a little-endian 128-bit integer.
+    Little,
+}
+
+/// Unit of the value of a [`UuidTimestamp`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TimeUnit {
+    /// 100-ns intervals since the UTC epoch of 1582-10-15, versions 1, 2 and 6.
+    Ticks,
+    /// Milliseconds since the Unix epoch, version 7.
+    Millis,
+}
+
+/// The timestamp embedded in a UUID together with the counter next to it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct UuidTimestamp {
+    /// The timestamp as stored in the UUID.
+    pub value: u64,
+    /// The unit of `value`.
+    pub unit: TimeUnit,
+    /// The clock sequence, or the 12 bits after the milliseconds of version 7.
+    pub counter: u16,
 }
 
 /// Represented by Coordinated Universal Time (UTC)
@@ -152,31 +462,62 @@ pub enum Version {
 #[derive(Debug, Eq, PartialEq, PartialOrd)]
 pub struct Timestamp(u64);
 
+#[cfg(feature = "std")]
 impl Default for Timestamp {
     fn default() -> Self {
         Self::new()
     }
 }
 
+#[cfg(feature = "std")]
 impl Timestamp {
     /// Generate UTC timestamp.
     pub fn new() -> Self {
+        Self::from_source(&SystemClock)
+    }
+}
+
+/// Where timestamps read the current time from, so tests and simulations
+/// can drive time based generation without the wall clock.
+pub trait ClockSource {
+    /// Returns the count of 100-ns intervals since the UTC epoch.
+    fn now_ticks(&self) -> u64;
+}
+
+/// The wall clock of the operating system.
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl ClockSource for SystemClock {
+    fn now_ticks(&self) -> u64 {
         let since_unix = SystemTime::now()
             .duration_since(SystemTime::UNIX_EPOCH)
             .unwrap();
+        unix_to_utc_ticks(since_unix.as_secs(), since_unix.subsec_nanos())
+    }
+}
 
-        // 100-ns intervals since Unix epoch
-        let intervals = since_unix.as_nanos() / 100;
+impl Timestamp {
+    /// Build the UTC timestamp of a Unix time given in seconds and nanoseconds.
+    pub fn from_unix(secs: u64, nanos: u32) -> Self {
+        Self(unix_to_utc_ticks(secs, nanos))
+    }
 
-        // Add UTC epoch offset
-        let utc = intervals + UTC_EPOCH as u128;
+    /// Read the current time from `clock`, saturating at the largest 60-bit
+    /// timestamp.
+    pub fn from_source<C: ClockSource>(clock: &C) -> Self {
+        Self(clock.now_ticks().min(TICKS_MASK))
+    }
 
-        // Only take lower 60 bits per RFC 4122
-        Self((utc & 0x0fff_ffff_ffff_ffff) as u64)
+    /// Returns the count of 100-ns intervals since the UTC epoch.
+    pub fn as_u64(&self) -> u64 {
+        self.0
     }
 }
 
-use std::ops::{BitAnd, Shr};
+use core::ops::{BitAnd, Shr};
 
 impl BitAnd<u64> for Timestamp {
     type Output = u64;
@@ -211,7 +552,7 @@ impl Shr<u32> for &Timestamp {
 }
 
 /// Is a 128-bit number used to identify information in computer systems.
-#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
 pub struct UUID([u8; 16]);
 
 impl UUID {
@@ -238,40 +579,505 @@ impl UUID {
         0x6b, 0xa7, 0xb8, 0x14, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30,
         0xc8,
     ]);
+
+    /// The nil UUID, all 128 bits set to zero.
+    pub const fn nil() -> Self {
+        UUID([0; 16])
+    }
+
+    /// The max UUID, all 128 bits set to one.
+    pub const fn max() -> Self {
+        UUID([0xff; 16])
+    }
+
+    /// Check whether this is the nil UUID.
+    pub fn is_nil(&self) -> bool {
+        self.0 == [0; 16]
+    }
+
+    /// Check whether this is the max UUID.
+    pub fn is_max(&self) -> bool {
+        self.0 == [0xff; 16]
+    }
+
+    /// Compare in constant time for UUIDs used as secrets, every byte is
+    /// inspected regardless of where the first difference is.
+    pub fn ct_eq(&self, other: &UUID) -> bool {
+        let diff = self
+            .0
+            .iter()
+            .zip(other.0.iter())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+        core::hint::black_box(diff) == 0
+    }
+
+    /// Get the version from the high nibble of byte 6.
+    pub fn version(&self) -> Option<Version> {
+        Version::from_u8(self.version_num())
+    }
+
+    /// Get the raw version number from 0 to 15 in the high nibble of byte 6.
+    pub fn version_num(&self) -> u8 {
+        self.0[6] >> 4
+    }
+
+    /// Get the variant from the high bits of byte 8.
+    pub fn variant(&self) -> Option<Variant> {
+        Variant::from_u8(self.0[8] >> 5)
+    }
+
+    /// Check the version nibble of byte 6 against `version`.
+    pub fn is_version(&self, version: Version) -> bool {
+        self.version_num() == version as u8
+    }
+
+    /// Check whether byte 8 carries the RFC 4122 variant bits `10`.
+    pub fn is_rfc_variant(&self) -> bool {
+        self.0[8] & 0xc0 == 0x80
+    }
+
+    /// Check whether this is a time based UUID (version 1).
+    pub fn is_v1(&self) -> bool {
+        self.is_version(Version::TIME)
+    }
+
+    /// Check whether this is a random UUID (version 4).
+    pub fn is_v4(&self) -> bool {
+        self.is_version(Version::RAND)
+    }
+
+    /// Check for the RFC variant and a version known to this crate, before
+    /// trusting the timestamp or node of a UUID from an untrusted source.
+    pub fn is_valid_rfc(&self) -> bool {
+        self.is_rfc_variant() && self.version().is_some()
+    }
+
+    /// Generate a UUID from caller-provided random bytes, only the version
+    /// and variant bits are overwritten.
+    pub fn v4_with_bytes(rand: [u8; 16]) -> Layout {
+        Layout {
+            field_low: ((rand[0] as u32) << 24)
+                | (rand[1] as u32) << 16
+                | (rand[2] as u32) << 8
+                | rand[3] as u32,
+            field_mid: (rand[4] as u16) << 8 | (rand[5] as u16),
+            field_high_and_version: ((rand[6] as u16) << 8 | (rand[7] as u16)) & 0xfff
+                | (Version::RAND as u16) << 12,
+            clock_seq_high_and_reserved: Variant::RFC.stamp(rand[8]),
+            clock_seq_low: rand[9],
+            node: [rand[10], rand[11], rand[12], rand[13], rand[14], rand[15]],
+        }
+    }
+
+    /// Generate a custom UUID from application-specific data, only the version
+    /// and variant bits are overwritten.
+    pub fn v8(data: [u8; 16]) -> Layout {
+        Layout {
+            field_low: u32::from_be_bytes([data[0], data[1], data[2], data[3]]),
+            field_mid: u16::from_be_bytes([data[4], data[5]]),
+            field_high_and_version: u16::from_be_bytes([data[6], data[7]]) & 0xfff
+                | (Version::CUSTOM as u16) << 12,
+            clock_seq_high_and_reserved: Variant::RFC.stamp(data[8]),
+            clock_seq_low: data[9],
+            node: [data[10], data[11], data[12], data[13], data[14], data[15]],
+        }
+    }
+
+    /// Check whether `payload` matches the CRC-32 stored by `v8_with_crc`,
+    /// returns `None` for UUIDs which are not version 8.
+    pub fn verify_crc(&self, payload: &[u8]) -> Option<bool> {
+        if self.0[6] >> 4 != Version::CUSTOM as u8 {
+            return None;
+        }
+        let crc = u32::from_be_bytes([self.0[0], self.0[1], self.0[2], self.0[3]]);
+        Some(crc == crc32(payload))
+    }
+
+    /// Read the account packed by `v8_account_scoped`, returns `None` for
+    /// UUIDs which are not version 8.
+    pub fn get_account(&self) -> Option<u64> {
+        if self.0[6] >> 4 != Version::CUSTOM as u8 {
+            return None;
+        }
+        let b = &self.0;
+        Some(
+            u64::from_be_bytes([0, 0, b[0], b[1], b[2], b[3], b[4], b[5]]) << 16
+                | ((b[6] & 0xf) as u64) << 12
+                | (b[7] as u64) << 4
+                | (b[8] & 0xf) as u64,
+        )
+    }
+
+    /// Split the UUID bytes back into the fields of a `Layout`.
+    pub fn decode(&self) -> Layout {
+        Layout {
+            field_low: u32::from_be_bytes([self.0[0], self.0[1], self.0[2], self.0[3]]),
+            field_mid: u16::from_be_bytes([self.0[4], self.0[5]]),
+            field_high_and_version: u16::from_be_bytes([self.0[6], self.0[7]]),
+            clock_seq_high_and_reserved: self.0[8],
+            clock_seq_low: self.0[9],
+            node: [
+                self.0[10], self.0[11], self.0[12], self.0[13], self.0[14], self.0[15],
+            ],
+        }
+    }
+
+    /// Returns the 16 bytes of this UUID in big-endian order.
+    pub const fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+
+    /// Returns the UUID as a 128-bit integer in big-endian order.
+    pub const fn as_u128(&self) -> u128 {
+        u128::from_be_bytes(self.0)
+    }
+
+    /// Creates a UUID from a 128-bit integer in big-endian order.
+    pub const fn from_u128(v: u128) -> Self {
+        UUID(v.to_be_bytes())
+    }
+
+    /// Returns the UUID as a 128-bit integer in little-endian order.
+    pub const fn as_u128_le(&self) -> u128 {
+        u128::from_le_bytes(self.0)
+    }
+
+    /// Creates a UUID from a 128-bit integer in little-endian order.
+    pub const fn from_u128_le(v: u128) -> Self {
+        UUID(v.to_le_bytes())
+    }
+
+    /// Returns the first 8 bytes as a big-endian `u64`, a stable value for
+    /// sharding.
+    pub const fn truncate_u64(&self) -> u64 {
+        (self.as_u128() >> 64) as u64
+    }
+
+    /// Returns both big-endian halves XOR-ed together, so every byte
+    /// contributes to the stable value.
+    pub const fn fold_u64(&self) -> u64 {
+        let v = self.as_u128();
+        (v >> 64) as u64 ^ v as u64
+    }
+
+    /// Assemble a UUID from its five field values in big-endian order,
+    /// usable in `const` items.
+    pub const fn from_fields(
+        field_low: u32,
+        field_mid: u16,
+        field_high_and_version: u16,
+        clock_seq: u16,
+        node: [u8; 6],
+    ) -> Self {
+        let low = field_low.to_be_bytes();
+        let mid = field_mid.to_be_bytes();
+        let high = field_high_and_version.to_be_bytes();
+        let seq = clock_seq.to_be_bytes();
+        UUID([
+            low[0], low[1], low[2], low[3], mid[0], mid[1], high[0], high[1], seq[0], seq[1],
+            node[0], node[1], node[2], node[3], node[4], node[5],
+        ])
+    }
+
+    /// Returns the UUID halfway between `a` and `b` as 128-bit integers, or
+    /// `None` when no UUID sorts strictly between them.
+    pub fn between(a: &UUID, b: &UUID) -> Option<UUID> {
+        let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+        let (lo, hi) = (lo.as_u128(), hi.as_u128());
+        if hi - lo < 2 {
+            return None;
+        }
+        Some(UUID::from_u128(lo + (hi - lo) / 2))
+    }
+
+    /// Returns the bytes in Microsoft GUID order, the first three fields
+    /// little-endian and the clock sequence and node unchanged.
+    pub const fn to_guid_bytes(&self) -> [u8; 16] {
+        let b = self.0;
+        [
+            b[3], b[2], b[1], b[0], b[5], b[4], b[7], b[6], b[8], b[9], b[10], b[11], b[12], b[13],
+            b[14], b[15],
+        ]
+    }
+
+    /// Create a UUID from bytes in Microsoft GUID order, as stored in a SQL
+    /// Server `uniqueidentifier` column.
+    pub const fn from_guid_bytes(bytes: [u8; 16]) -> UUID {
+        // The swap is its own inverse.
+        UUID(UUID(bytes).to_guid_bytes())
+    }
+
+    /// Returns all 16 bytes reversed, the little-endian bytes of the 128-bit
+    /// value. Unlike [`UUID::to_guid_bytes`] this also reverses the clock
+    /// sequence and node, and swaps the fields among each other.
+    pub const fn to_bytes_le(&self) -> [u8; 16] {
+        self.as_u128_le().to_be_bytes()
+    }
+
+    /// Create a UUID from all 16 bytes in reverse order, the inverse of
+    /// [`UUID::to_bytes_le`]; use [`UUID::from_guid_bytes`] for GUID order.
+    pub const fn from_bytes_le(bytes: [u8; 16]) -> UUID {
+        UUID::from_u128(u128::from_le_bytes(bytes))
+    }
+
+    /// Returns bytes whose order is the creation order, for indexing. A
+    /// version 1 UUID has its timestamp fields reordered most significant
+    /// first as in version 6, all other versions are returned unchanged.
+    pub fn to_sortable_bytes(&self) -> [u8; 16] {
+        let mut bytes = self.0;
+        if self.version() != Some(Version::TIME) {
+            return bytes;
+        }
+        let layout = self.decode();
+        let ticks = ((layout.field_high_and_version & 0xfff) as u64) << 48
+            | (layout.field_mid as u64) << 32
+            | layout.field_low as u64;
+        bytes[..4].copy_from_slice(&((ticks >> 28) as u32).to_be_bytes());
+        bytes[4..6].copy_from_slice(&((ticks >> 12) as u16).to_be_bytes());
+        bytes[6..8].copy_from_slice(
+            &((ticks & 0xfff) as u16 | (Version::REORDERED as u16) << 12).to_be_bytes(),
+        );
+        bytes
+    }
+
+    /// Convert every UUID from the `source` byte order to the canonical
+    /// big-endian order in place.
+    pub fn normalize_batch(uuids: &mut [UUID], source: Endianness) {
+        for uuid in uuids.iter_mut() {
+            match source {
+                Endianness::Big => {}
+                Endianness::Mixed => *uuid = UUID::from_guid_bytes(uuid.0),
+                Endianness::Little => uuid.0.reverse(),
+            }
+        }
+    }
+
+    /// Estimate the generation rate in UUIDs per second from a time-ordered
+    /// slice of version-7 UUIDs, other versions are ignored.
+    pub fn estimate_rate(uuids: &[UUID]) -> Option<f64> {
+        let mut millis = uuids.iter().filter_map(UUID::unix_millis);
+        let first = millis.next()?;
+        let (count, last) = millis.fold((1, first), |(count, _), t| (count + 1, t));
+        if count < 2 || last <= first {
+            return None;
+        }
+        Some((count - 1) as f64 * 1000.0 / (last - first) as f64)
+    }
+
+    /// Check whether the timestamp of a version-7 UUID falls within `[start, end)`,
+    /// returns `None` for other versions.
+    #[cfg(feature = "std")]
+    pub fn v7_in_range(&self, start: SystemTime, end: SystemTime) -> Option<bool> {
+        let millis = |t: SystemTime| {
+            t.duration_since(SystemTime::UNIX_EPOCH)
+                .map_or(0, |d| d.as_millis() as u64)
+        };
+        let t = self.unix_millis()?;
+        Some(millis(start) <= t && t < millis(end))
+    }
+
+    /// The smallest and largest clock sequence the process-wide generator has
+    /// used so far, the minimum exceeds the maximum until the first use.
+    pub fn clock_seq_stats() -> (u16, u16) {
+        (
+            CLOCK_SEQ_MIN.load(atomic::Ordering::Relaxed),
+            CLOCK_SEQ_MAX.load(atomic::Ordering::Relaxed),
+        )
+    }
+
+    /// The smallest and largest version-7 UUIDs for the millisecond of `time`,
+    /// with the random bits all zero and all one respectively.
+    #[cfg(feature = "std")]
+    pub fn v7_bounds(time: SystemTime) -> (UUID, UUID) {
+        let millis = time
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis() as u64);
+        let mut lower = [0u8; 16];
+        lower[..6].copy_from_slice(&millis.to_be_bytes()[2..]);
+        let mut upper = [0xff; 16];
+        upper[..6].copy_from_slice(&millis.to_be_bytes()[2..]);
+
+        lower[6] = (Version::UNIX as u8) << 4;
+        upper[6] = (Version::UNIX as u8) << 4 | 0x0f;
+        lower[8] = Variant::RFC.stamp(0x00);
+        upper[8] = Variant::RFC.stamp(0xff);
+        (UUID(lower), UUID(upper))
+    }
+
+    /// Write the 16 raw bytes to `w`.
+    #[cfg(feature = "std")]
+    pub fn write_to<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(&self.0)
+    }
+
+    /// Read exactly 16 raw bytes from `r`, failing with
+    /// `io::ErrorKind::UnexpectedEof` on fewer.
+    #[cfg(feature = "std")]
+    pub fn read_from<R: io::Read>(r: &mut R) -> io::Result<UUID> {
+        let mut bytes = [0u8; 16];
+        r.read_exact(&mut bytes)?;
+        Ok(UUID(bytes))
+    }
+
+    /// The 48-bit Unix timestamp in milliseconds of a version-7 UUID.
+    fn unix_millis(&self) -> Option<u64> {
+        if self.0[6] >> 4 != Version::UNIX as u8 {
+            return None;
+        }
+        Some(
+            (self.0[0] as u64) << 40
+                | (self.0[1] as u64) << 32
+                | (self.0[2] as u64) << 24
+                | (self.0[3] as u64) << 16
+                | (self.0[4] as u64) << 8
+                | (self.0[5] as u64),
+        )
+    }
+
+    /// Heuristically detect a UUID stored in the Microsoft GUID mixed-endian
+    /// byte order, true when only the swapped order yields `expected_version`.
+    pub fn looks_guid_swapped(&self, expected_version: Version) -> bool {
+        let v = expected_version as u8;
+        (self.0[7] >> 4) == v && (self.0[6] >> 4) != v
+    }
+}
+
+impl Default for UUID {
+    /// The nil UUID.
+    fn default() -> Self {
+        Self::nil()
+    }
+}
+
+impl AsRef<[u8]> for UUID {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<'a> IntoIterator for &'a UUID {
+    type Item = u8;
+    type IntoIter = core::iter::Copied<core::slice::Iter<'a, u8>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter().copied()
+    }
+}
+
+impl From<[u8; 16]> for UUID {
+    fn from(bytes: [u8; 16]) -> Self {
+        UUID(bytes)
+    }
+}
+
+impl From<Layout> for UUID {
+    fn from(layout: Layout) -> Self {
+        layout.to_uuid()
+    }
+}
+
+impl From<&Layout> for UUID {
+    fn from(layout: &Layout) -> Self {
+        layout.to_uuid()
+    }
+}
+
+impl From<&UUID> for UUID {
+    fn from(uuid: &UUID) -> Self {
+        *uuid
+    }
+}
+
+impl From<u128> for UUID {
+    fn from(v: u128) -> Self {
+        UUID::from_u128(v)
+    }
+}
+
+impl From<UUID> for u128 {
+    fn from(uuid: UUID) -> Self {
+        uuid.as_u128()
+    }
+}
+
+impl TryFrom<&[u8]> for UUID {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes
+            .try_into()
+            .map(UUID)
+            .map_err(|_| Error::InvalidLength(bytes.len()))
+    }
 }
 
 impl fmt::Display for UUID {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            fmt,
-            "{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
-            self.0[0],
-            self.0[1],
-            self.0[2],
-            self.0[3],
-            self.0[4],
-            self.0[5],
-            self.0[6],
-            self.0[7],
-            self.0[8],
-            self.0[9],
-            self.0[10],
-            self.0[11],
-            self.0[12],
-            self.0[13],
-            self.0[14],
-            self.0[15],
-        )
+        // `pad` honours width, fill and alignment.
+        fmt.pad(self.encode_lower(&mut [0; 36]))
+    }
+}
+
+impl fmt::Display for Layout {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_uuid(), fmt)
     }
 }
 
+/// Error returned when a UUID cannot be decoded from its textual forms.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Error {
+    /// The input does not have the length of a UUID, holds the found length.
+    InvalidLength(usize),
+    /// A non-hex character was found at the given index.
+    InvalidCharacter(usize),
+    /// A hyphen is missing or misplaced at the given index.
+    InvalidGroup(usize),
+    /// An opening brace without a closing one, or the reverse.
+    UnbalancedBraces,
+    /// The word at the given index is not in the mnemonic word list.
+    InvalidWord(usize),
+    /// The mnemonic checksum does not match its words.
+    InvalidChecksum,
+    /// No MAC address could be found for a time based UUID.
+    NoMacAddress,
+    /// A timestamp lies before the Unix epoch.
+    Underflow,
+    /// The byte is not a known DCE security domain.
+    InvalidDomain(u8),
+    /// A name based UUID was asked for under the nil namespace.
+    NilNamespace,
+    /// The token at the given index of a batch is not a valid UUID.
+    InvalidToken(usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidLength(len) => write!(fmt, "invalid length: found {}", len),
+            Error::InvalidCharacter(i) => write!(fmt, "invalid character at index {}", i),
+            Error::InvalidGroup(i) => write!(fmt, "expected hyphen at index {}", i),
+            Error::UnbalancedBraces => write!(fmt, "unbalanced braces"),
+            Error::InvalidWord(i) => write!(fmt, "unknown mnemonic word at index {}", i),
+            Error::InvalidChecksum => write!(fmt, "invalid mnemonic checksum"),
+            Error::NoMacAddress => write!(fmt, "no MAC address available"),
+            Error::Underflow => write!(fmt, "timestamp before the Unix epoch"),
+            Error::InvalidDomain(d) => write!(fmt, "unknown security domain {}", d),
+            Error::NilNamespace => write!(fmt, "nil namespace"),
+            Error::InvalidToken(i) => write!(fmt, "invalid UUID in token {}", i),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
 /// The clock sequence is used to help avoid duplicates that could arise when the
 /// clock is set backwards in time or if the node ID changes. According to RFC 4122,
 /// it is initialized with a random value when the UUID generator starts up.
 #[derive(Debug)]
 pub struct ClockSeq(pub u16);
 
-impl std::ops::Deref for ClockSeq {
+impl core::ops::Deref for ClockSeq {
     type Target = u16;
 
     fn deref(&self) -> &Self::Target {
@@ -279,6 +1085,16 @@ impl std::ops::Deref for ClockSeq {
     }
 }
 
+/// Marks the shared clock sequence as not yet seeded, outside the 14-bit range.
+const CLOCK_SEQ_UNSEEDED: u16 = u16::MAX;
+
+/// Shared clock sequence, seeded once and advanced on every use.
+static CLOCK_SEQ: atomic::AtomicU16 = atomic::AtomicU16::new(CLOCK_SEQ_UNSEEDED);
+
+/// Smallest and largest clock sequence handed out so far.
+static CLOCK_SEQ_MIN: atomic::AtomicU16 = atomic::AtomicU16::new(u16::MAX);
+static CLOCK_SEQ_MAX: atomic::AtomicU16 = atomic::AtomicU16::new(0);
+
 impl ClockSeq {
     /// Generate new clock sequence value, initialized with random bits and
     /// monotonically incrementing thereafter to prevent collisions.
@@ -289,18 +1105,55 @@ impl ClockSeq {
         // or if the node ID changes."
 
         // Only use 14 bits per spec, clear the variant bits
-        let initial = random_bits & 0x3fff;
-        Self(atomic::AtomicU16::new(initial).fetch_add(1, atomic::Ordering::AcqRel))
+        let next = |seq: u16| {
+            if seq == CLOCK_SEQ_UNSEEDED {
+                random_bits & 0x3fff
+            } else {
+                seq.wrapping_add(1) & 0x3fff
+            }
+        };
+        let prev = CLOCK_SEQ
+            .fetch_update(atomic::Ordering::AcqRel, atomic::Ordering::Acquire, |seq| {
+                Some(next(seq))
+            })
+            .unwrap_or_else(|seq| seq);
+        let seq = next(prev);
+        CLOCK_SEQ_MIN.fetch_min(seq, atomic::Ordering::Relaxed);
+        CLOCK_SEQ_MAX.fetch_max(seq, atomic::Ordering::Relaxed);
+        Self(seq)
+    }
+
+    /// Forget the shared clock sequence, the next one is seeded afresh.
+    #[cfg(all(test, feature = "rand"))]
+    pub(crate) fn reseed() {
+        CLOCK_SEQ.store(CLOCK_SEQ_UNSEEDED, atomic::Ordering::Release);
     }
 }
 
-/// The clock sequence is used to help avoid duplicates that could arise
-/// when the clock is set backwards in time or if the node ID changes.
+/// The 48-bit IEEE 802 node identifier of a time based UUID, usually a MAC-address.
 pub struct Node([u8; 6]);
 
-impl fmt::Display for Node {
-    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
+impl Node {
+    /// Create a node from its six octets.
+    pub const fn new(bytes: [u8; 6]) -> Self {
+        Node(bytes)
+    }
+
+    /// Returns the six octets of this node.
+    pub const fn as_bytes(&self) -> &[u8; 6] {
+        &self.0
+    }
+
+    /// Check whether the multicast bit of the first octet is set, which
+    /// marks a random node that can't be mistaken for a real MAC address.
+    pub fn is_multicast(&self) -> bool {
+        self.0[0] & 0x01 == 0x01
+    }
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
             fmt,
             "{:02x}-{:02x}-{:02x}-{:02x}-{:02x}-{:02x}",
             self.0[0], self.0[1], self.0[2], self.0[3], self.0[4], self.0[5],
@@ -328,6 +1181,129 @@ mod tests {
         assert_eq!(format!("{}", node).to_uppercase(), "00-2A-35-0D-13-80")
     }
 
+    #[test]
+    fn test_node_new() {
+        let node = Node::new([0x00, 0x2a, 0x35, 0x0d, 0x13, 0x80]);
+        assert_eq!(node.to_string(), "00-2a-35-0d-13-80");
+        assert_eq!(node.as_bytes(), &[0x00, 0x2a, 0x35, 0x0d, 0x13, 0x80]);
+        assert_eq!(
+            UUID::NAMESPACE_DNS.decode().get_mac().as_bytes(),
+            &[0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8]
+        );
+    }
+
+    #[test]
+    fn test_node_multicast() {
+        assert!(!Node([0x00, 0x2a, 0x35, 0x0d, 0x13, 0x80]).is_multicast());
+        assert!(Node([0x01, 0x2a, 0x35, 0x0d, 0x13, 0x80]).is_multicast());
+        assert!(!UUID::NAMESPACE_DNS.decode().get_mac().is_multicast());
+    }
+
+    #[test]
+    fn test_node_kind() {
+        let real = UUID::new_v1_at(
+            0x01d3_bfde_63b0_0000,
+            [0x00, 0x2a, 0x35, 0x0d, 0x13, 0x80],
+            0,
+        );
+        assert_eq!(real.node_kind(), NodeKind::Real);
+        assert_eq!(UUID::NAMESPACE_DNS.decode().node_kind(), NodeKind::Real);
+        let random = UUID::new_v1_at(
+            0x01d3_bfde_63b0_0000,
+            [0x03, 0x2a, 0x35, 0x0d, 0x13, 0x80],
+            0,
+        );
+        assert_eq!(random.node_kind(), NodeKind::Random);
+
+        #[cfg(feature = "rand")]
+        {
+            let _guard = time::STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+            assert_eq!(UUID::v1_random_node().node_kind(), NodeKind::Random);
+        }
+    }
+
+    #[test]
+    fn test_clock_seq_advances() {
+        #[cfg(feature = "rand")]
+        let _guard = time::STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let seqs: Vec<u16> = (0..8).map(|_| ClockSeq::new(0x1234).0).collect();
+
+        for (i, seq) in seqs.iter().enumerate() {
+            assert!(*seq <= 0x3fff);
+            assert!(!seqs[i + 1..].contains(seq));
+        }
+    }
+
+    #[test]
+    fn test_timestamp_from_source() {
+        struct FixedClock(u64);
+
+        impl ClockSource for FixedClock {
+            fn now_ticks(&self) -> u64 {
+                self.0
+            }
+        }
+
+        let clock = FixedClock(0x01d3_bfde_63b0_0000);
+        assert_eq!(
+            Timestamp::from_source(&clock).as_u64(),
+            0x01d3_bfde_63b0_0000
+        );
+        assert_eq!(
+            Timestamp::from_source(&clock),
+            Timestamp::from_source(&clock)
+        );
+        for ticks in [TICKS_MASK, 1 << 60, u64::MAX] {
+            assert_eq!(
+                Timestamp::from_source(&FixedClock(ticks)).as_u64(),
+                TICKS_MASK
+            );
+        }
+
+        #[cfg(feature = "std")]
+        assert!(Timestamp::from_source(&SystemClock).as_u64() > UTC_EPOCH);
+    }
+
+    #[test]
+    fn test_timestamp_from_unix() {
+        assert_eq!(Timestamp::from_unix(0, 0).as_u64(), UTC_EPOCH);
+        assert_eq!(
+            Timestamp::from_unix(1_700_000_000, 123_456_789).as_u64(),
+            UTC_EPOCH + 17_000_000_001_234_567
+        );
+    }
+
+    #[test]
+    fn test_utc_ticks() {
+        let max_secs = (TICKS_MASK - UTC_EPOCH) / 10_000_000;
+        for (secs, nanos) in [
+            (0, 0),
+            (1, 100),
+            (1_700_000_000, 123_456_700),
+            (max_secs, 0),
+            (
+                max_secs,
+                ((TICKS_MASK - UTC_EPOCH) % 10_000_000) as u32 * 100,
+            ),
+        ] {
+            let ticks = unix_to_utc_ticks(secs, nanos);
+            assert!(ticks <= TICKS_MASK);
+            assert_eq!(utc_ticks_to_unix(ticks), (secs, nanos));
+        }
+        assert_eq!(unix_to_utc_ticks(0, 0), UTC_EPOCH);
+        assert_eq!(unix_to_utc_ticks(0, 199), UTC_EPOCH + 1);
+        assert_eq!(utc_ticks_to_unix(UTC_EPOCH | 1 << 60), (0, 0));
+        assert_eq!(unix_to_utc_ticks(max_secs, 999_999_900), TICKS_MASK);
+        assert_eq!(unix_to_utc_ticks(max_secs + 1, 0), TICKS_MASK);
+        assert_eq!(unix_to_utc_ticks(u64::MAX, 999_999_999), TICKS_MASK);
+
+        // Ticks before the Unix epoch don't come back, they would have to wrap.
+        for ticks in [UTC_EPOCH, UTC_EPOCH + 1, TICKS_MASK - 1, TICKS_MASK] {
+            let (secs, nanos) = utc_ticks_to_unix(ticks);
+            assert_eq!(unix_to_utc_ticks(secs, nanos), ticks);
+        }
+    }
+
     #[test]
     fn test_is_valid_uuid() {
         let uuid_strings = [
@@ -342,4 +1318,773 @@ mod tests {
             assert!(is_valid(&id.to_uppercase()));
         }
     }
+
+    #[test]
+    fn test_nil_max() {
+        assert!(UUID::nil().is_nil());
+        assert!(UUID::max().is_max());
+        assert!(!UUID::nil().is_max());
+        assert!(!UUID::NAMESPACE_DNS.is_nil());
+        assert!(UUID::default().is_nil());
+        assert_eq!(
+            format!("{}", UUID::nil()),
+            "00000000-0000-0000-0000-000000000000"
+        );
+        assert_eq!(
+            format!("{}", UUID::max()),
+            "ffffffff-ffff-ffff-ffff-ffffffffffff"
+        );
+    }
+
+    #[test]
+    fn test_as_fields_le() {
+        let layout = UUID::NAMESPACE_DNS.decode();
+        assert_eq!(
+            layout.as_fields(),
+            (0x6ba7b810, 0x9dad, 0x11d1, 0x80b4, 0x00c0_4fd4_30c8)
+        );
+        assert_eq!(
+            layout.as_fields_le(),
+            (0x10b8a76b, 0xad9d, 0xd111, 0x80b4, 0x00c0_4fd4_30c8)
+        );
+
+        let guid = UUID::NAMESPACE_DNS.to_guid_bytes();
+        let (low, mid, high, _, _) = layout.as_fields_le();
+        assert_eq!(low.to_be_bytes(), guid[..4]);
+        assert_eq!(mid.to_be_bytes(), guid[4..6]);
+        assert_eq!(high.to_be_bytes(), guid[6..8]);
+    }
+
+    #[test]
+    fn test_display_padding() {
+        let uuid = UUID::NAMESPACE_DNS;
+        let padded = format!("{:<40}|", uuid);
+        assert_eq!(padded, "6ba7b810-9dad-11d1-80b4-00c04fd430c8    |");
+        assert_eq!(format!("{:>40}", uuid).len(), 40);
+        assert_eq!(
+            format!("{:*^40}", uuid),
+            "**6ba7b810-9dad-11d1-80b4-00c04fd430c8**"
+        );
+        assert_eq!(format!("{:10}", uuid), uuid.to_string());
+        assert_eq!(format!("{:<40}", uuid.decode()), format!("{:<40}", uuid));
+    }
+
+    #[test]
+    fn test_layout_display() {
+        let layout = UUID::NAMESPACE_DNS.decode();
+        assert_eq!(layout.to_string(), layout.to_uuid().to_string());
+        assert_eq!(
+            format!("{}", layout),
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c8"
+        );
+        #[cfg(feature = "rand")]
+        for layout in [UUID::v4(), UUID::v7()] {
+            assert_eq!(layout.to_string(), layout.to_uuid().to_string());
+        }
+    }
+
+    #[test]
+    fn test_layout_copy() {
+        use std::collections::HashSet;
+
+        let layout = UUID::NAMESPACE_DNS.decode();
+        let copy = layout;
+        assert_eq!(layout.clone(), copy);
+        assert_eq!(copy.to_uuid(), UUID::NAMESPACE_DNS);
+
+        let set: HashSet<Layout> = [layout, copy, UUID::NAMESPACE_URL.decode()].into();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_decode() {
+        let layout = Layout {
+            field_low: 0x6ba7_b810,
+            field_mid: 0x9dad,
+            field_high_and_version: 0x11d1,
+            clock_seq_high_and_reserved: 0x80,
+            clock_seq_low: 0xb4,
+            node: [0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8],
+        };
+
+        assert_eq!(UUID::NAMESPACE_DNS.decode(), layout);
+        assert_eq!(layout.to_uuid().decode(), layout);
+        assert_eq!(
+            UUID::NAMESPACE_DNS.decode().get_version(),
+            Some(Version::TIME)
+        );
+    }
+
+    #[test]
+    fn test_v4_with_bytes() {
+        let bytes = [0xff; 16];
+        let uuid = UUID::v4_with_bytes(bytes).to_uuid();
+        assert_eq!(uuid.0[6], 0x4f);
+        assert_eq!(uuid.0[8], 0xbf);
+
+        let mut expected = bytes;
+        expected[6] = uuid.0[6];
+        expected[8] = uuid.0[8];
+        assert_eq!(uuid.0, expected);
+    }
+
+    #[test]
+    fn test_verify_crc() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+
+        let mut data = [0x5a; 16];
+        data[..4].copy_from_slice(&0xcbf4_3926u32.to_be_bytes());
+        let uuid = UUID::v8(data).to_uuid();
+        assert_eq!(uuid.verify_crc(b"123456789"), Some(true));
+        assert_eq!(uuid.verify_crc(b"123456780"), Some(false));
+        assert_eq!(
+            UUID::v4_with_bytes(data).to_uuid().verify_crc(b"123456789"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_account() {
+        let mut data = [0u8; 16];
+        data[..9].copy_from_slice(&[0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0x0c, 0xde, 0x0f]);
+        let uuid = UUID::v8(data).to_uuid();
+        assert_eq!(uuid.get_account(), Some(0x0123_4567_89ab_cdef));
+        assert_eq!(UUID::v4_with_bytes(data).to_uuid().get_account(), None);
+    }
+
+    #[test]
+    fn test_v8() {
+        let data = [
+            0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54,
+            0x32, 0x10,
+        ];
+        let uuid = UUID::v8(data);
+        assert_eq!(uuid.get_version(), Some(Version::CUSTOM));
+        assert_eq!(uuid.get_variant(), Some(Variant::RFC));
+
+        let bytes = uuid.to_uuid().0;
+        for i in (0..16).filter(|i| *i != 6 && *i != 8) {
+            assert_eq!(bytes[i], data[i]);
+        }
+        assert_eq!(bytes[6] & 0x0f, data[6] & 0x0f);
+        assert_eq!(bytes[8] & 0x3f, data[8] & 0x3f);
+    }
+
+    #[test]
+    fn test_get_time() {
+        let mut layout = UUID::NAMESPACE_DNS.decode();
+        assert_eq!(layout.get_time(), Some(0x01d1_9dad_6ba7_b810 - UTC_EPOCH));
+
+        layout.field_high_and_version = 0x4000 | (layout.field_high_and_version & 0xfff);
+        assert_eq!(layout.get_time(), None);
+
+        layout.field_low = 0x1d19_dad6;
+        layout.field_mid = 0xba7b;
+        layout.field_high_and_version = 0x6810;
+        assert_eq!(layout.get_time(), Some(0x01d1_9dad_6ba7_b810 - UTC_EPOCH));
+
+        assert_eq!(UUID::nil().decode().get_time(), None);
+        assert_eq!(UUID::v8([0; 16]).get_time(), None);
+    }
+
+    #[test]
+    fn test_get_timestamp() {
+        let v1 = UUID::NAMESPACE_DNS.decode();
+        assert_eq!(
+            v1.get_timestamp(),
+            Some(UuidTimestamp {
+                value: 0x01d1_9dad_6ba7_b810,
+                unit: TimeUnit::Ticks,
+                counter: 0x00b4,
+            })
+        );
+
+        let v7: UUID = "017f22e2-79b0-7cc3-98c4-dc0c0c07398f".parse().unwrap();
+        assert_eq!(
+            v7.decode().get_timestamp(),
+            Some(UuidTimestamp {
+                value: 0x017f_22e2_79b0,
+                unit: TimeUnit::Millis,
+                counter: 0xcc3,
+            })
+        );
+        assert_eq!(v7.decode().get_time(), None);
+
+        let v4: UUID = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap();
+        assert_eq!(v4.decode().get_timestamp(), None);
+        assert_eq!(UUID::nil().decode().get_timestamp(), None);
+    }
+
+    #[test]
+    fn test_get_clock_seq() {
+        let uuid: UUID = "6ba7b810-9dad-11d1-80b4-00c04fd430c8".parse().unwrap();
+        assert_eq!(uuid.decode().get_clock_seq(), 0x00b4);
+
+        let layout = UUID::max().decode();
+        assert_eq!(layout.get_clock_seq(), 0x3fff);
+        for seq in [0, 0x0abc, 0x3fff] {
+            let uuid = UUID::v1_exact(Timestamp(0), seq, [0; 6]);
+            assert_eq!(uuid.get_clock_seq(), seq);
+        }
+    }
+
+    #[test]
+    fn test_set_version_variant() {
+        let mut layout = UUID::nil().decode();
+        layout.set_version(Version::RAND);
+        assert_eq!(layout.get_version(), Some(Version::RAND));
+        assert_eq!(layout.to_uuid().0, {
+            let mut bytes = [0u8; 16];
+            bytes[6] = 0x40;
+            bytes
+        });
+
+        layout.set_variant(Variant::RFC);
+        assert_eq!(layout.get_variant(), Some(Variant::RFC));
+        assert_eq!(
+            layout.to_uuid(),
+            UUID::from_u128(0x4000_8000_0000_0000_0000)
+        );
+
+        let mut layout = UUID::max().decode();
+        layout.set_version(Version::TIME);
+        layout.set_variant(Variant::NCS);
+        assert_eq!(layout.field_high_and_version, 0x1fff);
+        assert_eq!(layout.clock_seq_high_and_reserved, 0x7f);
+        assert_eq!(layout.clock_seq_low, 0xff);
+    }
+
+    #[test]
+    fn test_version_from_u8() {
+        let versions = [
+            Version::TIME,
+            Version::DCE,
+            Version::MD5,
+            Version::RAND,
+            Version::SHA1,
+            Version::REORDERED,
+            Version::UNIX,
+            Version::CUSTOM,
+        ];
+        for (nibble, version) in (1..).zip(versions) {
+            assert_eq!(Version::from_u8(nibble), Some(version));
+        }
+        assert_eq!(Version::from_u8(0), None);
+        assert_eq!(Version::from_u8(9), None);
+        assert_eq!(Version::from_u8(0xff), None);
+    }
+
+    #[test]
+    fn test_version_num() {
+        let mut bytes = [0u8; 16];
+        bytes[6] = 0x7a;
+        let uuid = UUID(bytes);
+        assert_eq!(uuid.version_num(), 7);
+        assert_eq!(uuid.version(), Some(Version::UNIX));
+        assert_eq!(uuid.decode().get_version_num(), 7);
+        assert_eq!(uuid.decode().get_version(), Some(Version::UNIX));
+
+        // An unknown version stays distinguishable from a malformed UUID.
+        bytes[6] = 0xb0;
+        let uuid = UUID(bytes);
+        assert_eq!(uuid.version(), None);
+        assert_eq!(uuid.version_num(), 11);
+        assert_eq!(uuid.decode().get_version_num(), 11);
+        assert_eq!(UUID::nil().version_num(), 0);
+        assert_eq!(UUID::max().version_num(), 15);
+    }
+
+    #[test]
+    fn test_domain_from_u8() {
+        assert_eq!(Domain::from_u8(0), Some(Domain::PERSON));
+        assert_eq!(Domain::from_u8(1), Some(Domain::GROUP));
+        assert_eq!(Domain::from_u8(2), Some(Domain::ORG));
+        assert_eq!(Domain::from_u8(3), None);
+        assert_eq!(UUID::NAMESPACE_DNS.decode().get_domain(), None);
+    }
+
+    #[test]
+    fn test_domain_try_from() {
+        for domain in [Domain::PERSON, Domain::GROUP, Domain::ORG] {
+            assert_eq!(Domain::try_from(domain as u8), Ok(domain));
+        }
+        assert_eq!(Domain::try_from(3), Err(Error::InvalidDomain(3)));
+        assert_eq!(Domain::try_from(0xff), Err(Error::InvalidDomain(0xff)));
+    }
+
+    #[test]
+    fn test_variant_from_u8() {
+        for bits in 0b000..=0b011 {
+            assert_eq!(Variant::from_u8(bits), Some(Variant::NCS));
+        }
+        assert_eq!(Variant::from_u8(0b100), Some(Variant::RFC));
+        assert_eq!(Variant::from_u8(0b101), Some(Variant::RFC));
+        assert_eq!(Variant::from_u8(0b110), Some(Variant::MS));
+        assert_eq!(Variant::from_u8(0b111), Some(Variant::FUT));
+        assert_eq!(Variant::from_u8(0b1000), None);
+    }
+
+    #[test]
+    fn test_get_variant_table() {
+        // The variant is a prefix of one to three bits: 0xx, 10x, 110, 111.
+        for (high, variant) in [
+            (0x00, Variant::NCS),
+            (0x7f, Variant::NCS),
+            (0x80, Variant::RFC),
+            (0x9f, Variant::RFC),
+            (0xa0, Variant::RFC),
+            (0xbf, Variant::RFC),
+            (0xc0, Variant::MS),
+            (0xdf, Variant::MS),
+            (0xe0, Variant::FUT),
+            (0xff, Variant::FUT),
+        ] {
+            let mut bytes = [0u8; 16];
+            bytes[8] = high;
+            assert_eq!(
+                UUID(bytes).decode().get_variant(),
+                Some(variant),
+                "{:#04x}",
+                high
+            );
+            assert_eq!(UUID(bytes).variant(), UUID(bytes).decode().get_variant());
+        }
+        for high in 0..=0xffu8 {
+            let mut bytes = [0u8; 16];
+            bytes[8] = high;
+            assert_eq!(
+                UUID(bytes).decode().get_variant() == Some(Variant::RFC),
+                UUID(bytes).is_rfc_variant()
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_read_write() {
+        use std::io::Cursor;
+
+        let uuids = [UUID::NAMESPACE_DNS, UUID::nil(), UUID::max()];
+        let mut buf = Vec::new();
+        for uuid in uuids {
+            uuid.write_to(&mut buf).unwrap();
+        }
+        assert_eq!(buf.len(), 48);
+        assert_eq!(buf[..16], UUID::NAMESPACE_DNS.0);
+
+        let mut cursor = Cursor::new(buf);
+        for uuid in uuids {
+            assert_eq!(UUID::read_from(&mut cursor).unwrap(), uuid);
+        }
+        let err = UUID::read_from(&mut cursor).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+
+        let mut short = Cursor::new(vec![0u8; 15]);
+        assert!(UUID::read_from(&mut short).is_err());
+    }
+
+    #[test]
+    fn test_bytes_le() {
+        let uuid = UUID::NAMESPACE_DNS;
+        let le = uuid.to_bytes_le();
+        let mut reversed = uuid.0;
+        reversed.reverse();
+        assert_eq!(le, reversed);
+        assert_eq!(UUID::from_bytes_le(le), uuid);
+        assert_ne!(le, uuid.to_guid_bytes());
+        assert_ne!(UUID::from_bytes_le(le), UUID::from_guid_bytes(le));
+
+        for uuid in [UUID::nil(), UUID::max(), UUID::NAMESPACE_URL] {
+            assert_eq!(UUID::from_bytes_le(uuid.to_bytes_le()), uuid);
+        }
+    }
+
+    #[test]
+    fn test_guid_bytes() {
+        let uuid: UUID = "00112233-4455-6677-8899-aabbccddeeff".parse().unwrap();
+        let guid = [
+            0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+            0xee, 0xff,
+        ];
+        assert_eq!(uuid.to_guid_bytes(), guid);
+        assert_eq!(UUID::from_guid_bytes(guid), uuid);
+        assert_eq!(
+            UUID::from_guid_bytes(UUID::NAMESPACE_DNS.to_guid_bytes()),
+            UUID::NAMESPACE_DNS
+        );
+    }
+
+    #[test]
+    fn test_as_ref_iter() {
+        let uuid = UUID::NAMESPACE_DNS;
+        let slice: &[u8] = uuid.as_ref();
+        assert_eq!(slice.len(), 16);
+        assert_eq!(slice, uuid.as_bytes());
+
+        let bytes: Vec<u8> = (&uuid).into_iter().collect();
+        assert_eq!(bytes, uuid.as_bytes());
+        let mut i = 0;
+        for b in &uuid {
+            assert_eq!(b, uuid.as_bytes()[i]);
+            i += 1;
+        }
+        assert_eq!(i, 16);
+    }
+
+    #[test]
+    fn test_try_get_time() {
+        assert_eq!(
+            UUID::NAMESPACE_DNS.decode().try_get_time(),
+            Ok(UUID::NAMESPACE_DNS.decode().get_time())
+        );
+        assert_eq!(UUID::v8([0; 16]).try_get_time(), Ok(None));
+
+        let mut bytes = [0u8; 16];
+        bytes[6] = 0x10;
+        bytes[8] = 0x80;
+        assert_eq!(UUID(bytes).decode().try_get_time(), Err(Error::Underflow));
+    }
+
+    #[test]
+    fn test_error_display() {
+        for (error, message) in [
+            (Error::InvalidLength(35), "invalid length: found 35"),
+            (Error::InvalidCharacter(7), "invalid character at index 7"),
+            (Error::InvalidGroup(8), "expected hyphen at index 8"),
+            (Error::UnbalancedBraces, "unbalanced braces"),
+            (Error::InvalidWord(3), "unknown mnemonic word at index 3"),
+            (Error::InvalidChecksum, "invalid mnemonic checksum"),
+            (Error::NoMacAddress, "no MAC address available"),
+            (Error::Underflow, "timestamp before the Unix epoch"),
+            (Error::InvalidDomain(3), "unknown security domain 3"),
+            (Error::NilNamespace, "nil namespace"),
+            (Error::InvalidToken(2), "invalid UUID in token 2"),
+        ] {
+            assert_eq!(error.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let bytes = *UUID::NAMESPACE_DNS.as_bytes();
+        assert_eq!(UUID::from(bytes), UUID::NAMESPACE_DNS);
+        assert_eq!(UUID::try_from(&bytes[..]), Ok(UUID::NAMESPACE_DNS));
+        assert_eq!(UUID::try_from(&bytes[..15]), Err(Error::InvalidLength(15)));
+        assert_eq!(
+            UUID::try_from(&[0u8; 17][..]),
+            Err(Error::InvalidLength(17))
+        );
+    }
+
+    #[test]
+    fn test_u128() {
+        let v = 0x6ba7b810_9dad_11d1_80b4_00c04fd430c8;
+        assert_eq!(UUID::NAMESPACE_DNS.as_u128(), v);
+        assert_eq!(UUID::from_u128(v), UUID::NAMESPACE_DNS);
+        assert_eq!(UUID::NAMESPACE_DNS.as_u128_le(), v.swap_bytes());
+        assert_eq!(UUID::from_u128_le(v.swap_bytes()), UUID::NAMESPACE_DNS);
+
+        for v in [0, 1, u128::MAX, v] {
+            assert_eq!(UUID::from_u128(v).as_u128(), v);
+            assert_eq!(UUID::from_u128_le(v).as_u128_le(), v);
+        }
+    }
+
+    #[test]
+    fn test_u64_fingerprint() {
+        assert_eq!(UUID::NAMESPACE_DNS.truncate_u64(), 0x6ba7_b810_9dad_11d1);
+        assert_eq!(UUID::NAMESPACE_DNS.fold_u64(), 0xeb13_b8d0_d279_2119);
+        assert_eq!(UUID::nil().fold_u64(), 0);
+        assert_eq!(UUID::max().truncate_u64(), u64::MAX);
+        assert_eq!(UUID::max().fold_u64(), 0);
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        let pairs = [
+            (UUID::NAMESPACE_DNS, UUID::NAMESPACE_DNS),
+            (UUID::NAMESPACE_DNS, UUID::NAMESPACE_URL),
+            (UUID::nil(), UUID::max()),
+            (UUID::nil(), UUID::from_u128(1)),
+            (UUID::max(), UUID::from_u128(u128::MAX >> 1)),
+        ];
+        for (a, b) in pairs {
+            assert_eq!(a.ct_eq(&b), a == b);
+            assert_eq!(b.ct_eq(&a), a == b);
+        }
+    }
+
+    #[test]
+    fn test_version_variant() {
+        let v1: UUID = "6ba7b810-9dad-11d1-80b4-00c04fd430c8".parse().unwrap();
+        assert_eq!(v1.version(), Some(Version::TIME));
+        assert_eq!(v1.variant(), Some(Variant::RFC));
+        assert_eq!(v1.version(), v1.decode().get_version());
+        assert_eq!(v1.variant(), v1.decode().get_variant());
+
+        let v4: UUID = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap();
+        assert_eq!(v4.version(), Some(Version::RAND));
+        assert_eq!(v4.variant(), Some(Variant::RFC));
+        assert_eq!(v4.version(), v4.decode().get_version());
+        assert_eq!(v4.variant(), v4.decode().get_variant());
+
+        assert_eq!(UUID::nil().version(), None);
+        assert_eq!(UUID::max().variant(), Some(Variant::FUT));
+    }
+
+    #[test]
+    fn test_is_valid_rfc() {
+        let v4: UUID = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap();
+        assert!(v4.is_valid_rfc());
+        assert!(UUID::NAMESPACE_DNS.is_valid_rfc());
+
+        let ncs: UUID = "67e55044-10b1-426f-1247-bb680e5fe0c8".parse().unwrap();
+        assert_eq!(ncs.variant(), Some(Variant::NCS));
+        assert!(!ncs.is_valid_rfc());
+
+        let unknown: UUID = "67e55044-10b1-c26f-9247-bb680e5fe0c8".parse().unwrap();
+        assert_eq!(unknown.version(), None);
+        assert!(!unknown.is_valid_rfc());
+
+        assert!(!UUID::nil().is_valid_rfc());
+        assert!(!UUID::max().is_valid_rfc());
+    }
+
+    #[test]
+    fn test_version_predicates() {
+        let v1: UUID = "6ba7b810-9dad-11d1-80b4-00c04fd430c8".parse().unwrap();
+        assert!(v1.is_v1() && !v1.is_v4());
+        assert!(v1.is_version(Version::TIME));
+        assert!(v1.is_rfc_variant());
+
+        let v4: UUID = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap();
+        assert!(v4.is_v4() && !v4.is_v1());
+        assert!(!v4.is_version(Version::TIME));
+        assert!(v4.is_rfc_variant());
+
+        let nil = UUID::nil();
+        assert!(!nil.is_v1() && !nil.is_v4());
+        for version in [
+            Version::TIME,
+            Version::DCE,
+            Version::MD5,
+            Version::RAND,
+            Version::SHA1,
+            Version::REORDERED,
+            Version::UNIX,
+            Version::CUSTOM,
+        ] {
+            assert!(!nil.is_version(version));
+        }
+        assert!(!nil.is_rfc_variant());
+        assert!(!UUID::max().is_rfc_variant());
+    }
+
+    #[test]
+    fn test_from_fields() {
+        const DNS: UUID = UUID::from_fields(
+            0x6ba7b810,
+            0x9dad,
+            0x11d1,
+            0x80b4,
+            [0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8],
+        );
+        assert_eq!(DNS, UUID::NAMESPACE_DNS);
+        assert_eq!(DNS.decode().to_uuid(), DNS);
+    }
+
+    #[test]
+    fn test_u128_into() {
+        let mut v: u128 = 0x6ba7b810_9dad_11d1_80b4_00c04fd430c8;
+        for _ in 0..64 {
+            let uuid: UUID = v.into();
+            let n: u128 = uuid.into();
+            assert_eq!(n, v);
+            assert_eq!(uuid, UUID::from_u128(v));
+            v = v.wrapping_mul(0x9e37_79b9_7f4a_7c15).rotate_left(17);
+        }
+    }
+
+    #[test]
+    fn test_hash_set() {
+        use std::collections::HashSet;
+
+        let uuids = [
+            UUID::NAMESPACE_DNS,
+            UUID::NAMESPACE_OID,
+            UUID::NAMESPACE_URL,
+            UUID::NAMESPACE_X500,
+        ];
+        let set: HashSet<UUID> = uuids.iter().copied().collect();
+
+        assert_eq!(set.len(), uuids.len());
+        for uuid in uuids.iter() {
+            assert!(set.contains(uuid));
+        }
+        assert!(!set.contains(&UUID::nil()));
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut uuids = vec![
+            UUID::max(),
+            UUID::NAMESPACE_X500,
+            UUID::NAMESPACE_DNS,
+            UUID::nil(),
+            UUID::NAMESPACE_URL,
+            UUID::NAMESPACE_OID,
+        ];
+        uuids.sort();
+
+        assert_eq!(
+            uuids,
+            [
+                UUID::nil(),
+                UUID::NAMESPACE_DNS,
+                UUID::NAMESPACE_URL,
+                UUID::NAMESPACE_OID,
+                UUID::NAMESPACE_X500,
+                UUID::max(),
+            ]
+        );
+        for pair in uuids.windows(2) {
+            assert!(pair[0].0 < pair[1].0);
+        }
+    }
+
+    #[test]
+    fn test_between() {
+        let pairs = [
+            (UUID::nil(), UUID::max()),
+            (UUID::NAMESPACE_DNS, UUID::NAMESPACE_X500),
+            (UUID::NAMESPACE_OID, UUID::NAMESPACE_URL),
+        ];
+        for (a, b) in pairs.iter() {
+            let mid = UUID::between(a, b).unwrap();
+            assert!(a.min(b) < &mid && &mid < a.max(b));
+            assert_eq!(UUID::between(b, a), Some(mid));
+        }
+
+        assert_eq!(UUID::between(&UUID::nil(), &UUID::nil()), None);
+        assert_eq!(UUID::between(&UUID::nil(), &UUID::from_u128(1)), None);
+        assert_eq!(
+            UUID::between(&UUID::nil(), &UUID::from_u128(2)),
+            Some(UUID::from_u128(1))
+        );
+    }
+
+    #[test]
+    fn test_normalize_batch() {
+        // NAMESPACE_DNS and NAMESPACE_URL in GUID byte order.
+        let mut uuids = [
+            UUID([
+                0x10, 0xb8, 0xa7, 0x6b, 0xad, 0x9d, 0xd1, 0x11, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4,
+                0x30, 0xc8,
+            ]),
+            UUID([
+                0x11, 0xb8, 0xa7, 0x6b, 0xad, 0x9d, 0xd1, 0x11, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4,
+                0x30, 0xc8,
+            ]),
+        ];
+        UUID::normalize_batch(&mut uuids, Endianness::Mixed);
+        assert_eq!(uuids, [UUID::NAMESPACE_DNS, UUID::NAMESPACE_URL]);
+
+        UUID::normalize_batch(&mut uuids, Endianness::Big);
+        assert_eq!(uuids, [UUID::NAMESPACE_DNS, UUID::NAMESPACE_URL]);
+
+        let mut reversed = [UUID::from_u128_le(UUID::NAMESPACE_OID.as_u128())];
+        UUID::normalize_batch(&mut reversed, Endianness::Little);
+        assert_eq!(reversed, [UUID::NAMESPACE_OID]);
+    }
+
+    fn v7_at(ms: u64) -> UUID {
+        let mut bytes = [0u8; 16];
+        bytes[..6].copy_from_slice(&ms.to_be_bytes()[2..]);
+        bytes[6] = 0x70;
+        bytes[8] = 0x80;
+        UUID(bytes)
+    }
+
+    #[test]
+    fn test_estimate_rate() {
+        // 11 UUIDs spread over two seconds.
+        let uuids: Vec<UUID> = (0..=10)
+            .map(|i| v7_at(1_700_000_000_000 + i * 200))
+            .collect();
+        assert_eq!(UUID::estimate_rate(&uuids), Some(5.0));
+        assert_eq!(UUID::estimate_rate(&uuids[..1]), None);
+        assert_eq!(UUID::estimate_rate(&[UUID::NAMESPACE_DNS, uuids[0]]), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_v7_in_range() {
+        use std::time::Duration;
+
+        let start = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+        let end = start + Duration::from_secs(1);
+
+        assert_eq!(v7_at(1_700_000_000_000).v7_in_range(start, end), Some(true));
+        assert_eq!(v7_at(1_700_000_000_999).v7_in_range(start, end), Some(true));
+        assert_eq!(
+            v7_at(1_700_000_001_000).v7_in_range(start, end),
+            Some(false)
+        );
+        assert_eq!(
+            v7_at(1_699_999_999_999).v7_in_range(start, end),
+            Some(false)
+        );
+        assert_eq!(UUID::NAMESPACE_DNS.v7_in_range(start, end), None);
+    }
+
+    #[test]
+    fn test_looks_guid_swapped() {
+        // 550e8400-e29b-41d4-a716-446655440000 in GUID byte order.
+        let guid = UUID([
+            0x00, 0x84, 0x0e, 0x55, 0x9b, 0xe2, 0xd4, 0x41, 0xa7, 0x16, 0x44, 0x66, 0x55, 0x44,
+            0x00, 0x00,
+        ]);
+        let uuid = UUID([
+            0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4, 0xa7, 0x16, 0x44, 0x66, 0x55, 0x44,
+            0x00, 0x00,
+        ]);
+
+        assert!(guid.looks_guid_swapped(Version::RAND));
+        assert!(!uuid.looks_guid_swapped(Version::RAND));
+    }
+
+    #[test]
+    fn test_to_sortable_bytes() {
+        let node = [0x03, 0x2a, 0x35, 0x0d, 0x13, 0x80];
+        // The low timestamp field wraps between the two, so v1 byte order is reversed.
+        let first = UUID::new_v1_at(0x01d3_bfde_ffff_ffff, node, 0x0abc).to_uuid();
+        let second = UUID::new_v1_at(0x01d3_bfdf_0000_0000, node, 0x0abc).to_uuid();
+        assert!(first > second);
+        assert!(first.to_sortable_bytes() < second.to_sortable_bytes());
+
+        let sortable = UUID(first.to_sortable_bytes());
+        assert_eq!(sortable.version(), Some(Version::REORDERED));
+        assert_eq!(sortable.to_string(), "1d3bfdef-ffff-6fff-8abc-032a350d1380");
+
+        let other = UUID::from_u128(0x0189_7c4c_e2a0_7abc_8def_0123_4567_89ab);
+        assert_eq!(other.to_sortable_bytes(), *other.as_bytes());
+    }
+
+    #[test]
+    fn test_from_layout() {
+        let layout = UUID::NAMESPACE_OID.decode();
+        let uuid: UUID = layout.into();
+        assert_eq!(uuid, UUID::NAMESPACE_OID);
+        assert_eq!(UUID::from(&layout), layout.to_uuid());
+        #[allow(deprecated)]
+        let bytes = layout.as_bytes();
+        assert_eq!(uuid, bytes);
+
+        #[cfg(feature = "rand")]
+        {
+            let layout = UUID::v4();
+            let uuid: UUID = layout.into();
+            assert_eq!(uuid, layout.to_uuid());
+        }
+    }
 }
@@ -0,0 +1,125 @@
+//! BIP39 mnemonics, the 128 bits of a UUID are encoded as 12 words from the
+//! English BIP39 word list, the last word carrying a 4-bit SHA-256 checksum.
+
+#![cfg(feature = "mnemonic")]
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use sha2::{Digest, Sha256};
+
+use crate::{Error, UUID};
+
+/// The English BIP39 word list, 2048 words in sorted order.
+const WORDS: &str = include_str!("english.txt");
+
+/// Number of words encoding 128 bits plus the 4-bit checksum.
+const WORD_COUNT: usize = 12;
+
+impl UUID {
+    /// Encode this UUID as 12 BIP39 words.
+    pub fn to_mnemonic(&self) -> Vec<String> {
+        let bits = self.as_u128();
+        (0..WORD_COUNT)
+            .map(|i| {
+                // 11 bits per word, the last word ends with the 4-bit checksum.
+                let index = if i + 1 < WORD_COUNT {
+                    (bits >> (117 - 11 * i)) as usize & 0x7ff
+                } else {
+                    (bits as usize & 0x7f) << 4 | checksum(&self.0) as usize
+                };
+                WORDS.lines().nth(index).unwrap().to_string()
+            })
+            .collect()
+    }
+
+    /// Decode a UUID from 12 BIP39 words, validating the checksum.
+    pub fn from_mnemonic(words: &[&str]) -> Result<UUID, Error> {
+        if words.len() != WORD_COUNT {
+            return Err(Error::InvalidLength(words.len()));
+        }
+
+        let mut bits: u128 = 0;
+        let mut sum: u8 = 0;
+        for (i, word) in words.iter().enumerate() {
+            let index = WORDS
+                .lines()
+                .position(|w| w == *word)
+                .ok_or(Error::InvalidWord(i))? as u128;
+            if i + 1 < WORD_COUNT {
+                bits = bits << 11 | index;
+            } else {
+                bits = bits << 7 | index >> 4;
+                sum = (index & 0xf) as u8;
+            }
+        }
+
+        let uuid = UUID::from_u128(bits);
+        if checksum(&uuid.0) != sum {
+            return Err(Error::InvalidChecksum);
+        }
+        Ok(uuid)
+    }
+}
+
+/// The first 4 bits of the SHA-256 of the entropy.
+fn checksum(bytes: &[u8; 16]) -> u8 {
+    Sha256::digest(bytes)[0] >> 4
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mnemonic_vectors() {
+        let vectors = [
+            (UUID::nil(), "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"),
+            (UUID::from_u128(0x7f7f7f7f_7f7f_7f7f_7f7f_7f7f7f7f7f7f), "legal winner thank year wave sausage worth useful legal winner thank yellow"),
+            (UUID::max(), "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong"),
+        ];
+        for (uuid, phrase) in vectors.iter() {
+            assert_eq!(uuid.to_mnemonic().join(" "), *phrase);
+            let words: Vec<&str> = phrase.split(' ').collect();
+            assert_eq!(UUID::from_mnemonic(&words), Ok(*uuid));
+        }
+    }
+
+    #[test]
+    fn test_mnemonic_round_trip() {
+        for uuid in [
+            UUID::NAMESPACE_DNS,
+            UUID::NAMESPACE_OID,
+            UUID::NAMESPACE_URL,
+            UUID::NAMESPACE_X500,
+        ] {
+            let mnemonic = uuid.to_mnemonic();
+            let words: Vec<&str> = mnemonic.iter().map(String::as_str).collect();
+            assert_eq!(UUID::from_mnemonic(&words), Ok(uuid));
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_mnemonic_random() {
+        for _ in 0..100 {
+            let uuid = UUID::v4().to_uuid();
+            let mnemonic = uuid.to_mnemonic();
+            let words: Vec<&str> = mnemonic.iter().map(String::as_str).collect();
+            assert_eq!(UUID::from_mnemonic(&words), Ok(uuid));
+        }
+    }
+
+    #[test]
+    fn test_mnemonic_invalid() {
+        let mut words = ["abandon"; 12];
+        assert_eq!(
+            UUID::from_mnemonic(&words[..11]),
+            Err(Error::InvalidLength(11))
+        );
+        assert_eq!(UUID::from_mnemonic(&words), Err(Error::InvalidChecksum));
+
+        words[3] = "uuid";
+        assert_eq!(UUID::from_mnemonic(&words), Err(Error::InvalidWord(3)));
+    }
+}
@@ -1,34 +1,218 @@
-#![cfg(feature = "hmd5")]
-#![cfg(feature = "hsha1")]
+#![cfg(any(feature = "hmd5", feature = "hsha1", feature = "hsha256"))]
 
+#[cfg(feature = "hmd5")]
 use md5;
+#[cfg(feature = "hsha1")]
 use sha1_smol::Sha1;
+#[cfg(feature = "hsha256")]
+use sha2::{Digest, Sha256};
 
+#[cfg(feature = "hsha1")]
+use core::fmt::{self, Write};
+#[cfg(feature = "hsha1")]
+use core::net::SocketAddr;
+
+#[cfg(all(feature = "hsha1", feature = "alloc"))]
+use alloc::{string::String, vec::Vec};
+#[cfg(all(feature = "hsha1", feature = "std"))]
+use std::io;
+
+#[cfg(any(feature = "hmd5", feature = "hsha1"))]
+use crate::Error;
 use crate::{Layout, Variant, Version, UUID};
 
 impl UUID {
-    /// Generate a UUID by hashing a namespace identifier and name uses MD5.
+    /// Generate a UUID by hashing a namespace identifier and name uses MD5,
+    /// the namespace may be passed by value or by reference.
     #[cfg(feature = "hmd5")]
-    pub fn v3(any: &str, namespace: UUID) -> Layout {
-        let hash = md5::compute(Self::data(any, namespace)).0;
-        Layout {
-            field_low: ((hash[0] as u32) << 24)
-                | (hash[1] as u32) << 16
-                | (hash[2] as u32) << 8
-                | hash[3] as u32,
-            field_mid: (hash[4] as u16) << 8 | (hash[5] as u16),
-            field_high_and_version: ((hash[6] as u16) << 8 | (hash[7] as u16)) & 0xfff
-                | (Version::MD5 as u16) << 12,
-            clock_seq_high_and_reserved: (hash[8] & 0xf) | (Variant::RFC as u8) << 4,
-            clock_seq_low: hash[9] as u8,
-            node: [hash[10], hash[11], hash[12], hash[13], hash[14], hash[15]],
+    pub fn v3(any: impl AsRef<[u8]>, namespace: impl Into<UUID>) -> UUID {
+        Self::v3_layout(any, namespace).to_uuid()
+    }
+
+    /// Generate a UUID by hashing a namespace identifier and name uses SHA1,
+    /// the namespace may be passed by value or by reference.
+    #[cfg(feature = "hsha1")]
+    pub fn v5(any: impl AsRef<[u8]>, namespace: impl Into<UUID>) -> UUID {
+        Self::v5_layout(any, namespace).to_uuid()
+    }
+
+    /// Like [`UUID::v3_layout`], but a nil namespace, almost always an
+    /// uninitialized constant, is an `Error::NilNamespace`.
+    #[cfg(feature = "hmd5")]
+    pub fn v3_checked(any: impl AsRef<[u8]>, namespace: impl Into<UUID>) -> Result<Layout, Error> {
+        let namespace = namespace.into();
+        if namespace.is_nil() {
+            return Err(Error::NilNamespace);
+        }
+        Ok(Self::v3_layout(any, namespace))
+    }
+
+    /// Like [`UUID::v5_layout`], but a nil namespace, almost always an
+    /// uninitialized constant, is an `Error::NilNamespace`.
+    #[cfg(feature = "hsha1")]
+    pub fn v5_checked(any: impl AsRef<[u8]>, namespace: impl Into<UUID>) -> Result<Layout, Error> {
+        let namespace = namespace.into();
+        if namespace.is_nil() {
+            return Err(Error::NilNamespace);
+        }
+        Ok(Self::v5_layout(any, namespace))
+    }
+
+    /// Like [`UUID::v3`], but returns the decoded `Layout`.
+    #[cfg(feature = "hmd5")]
+    pub fn v3_layout(any: impl AsRef<[u8]>, namespace: impl Into<UUID>) -> Layout {
+        Self::hashed(&Self::raw_md5(any.as_ref(), namespace.into()), Version::MD5)
+    }
+
+    /// Like [`UUID::v5`], but returns the decoded `Layout`.
+    #[cfg(feature = "hsha1")]
+    pub fn v5_layout(any: impl AsRef<[u8]>, namespace: impl Into<UUID>) -> Layout {
+        Self::sha1(&[&namespace.into().0, any.as_ref()])
+    }
+
+    /// Generate a UUID uses SHA1 over a name given as several parts, hashed
+    /// in order after the namespace as if they were concatenated.
+    #[cfg(feature = "hsha1")]
+    pub fn v5_parts(namespace: UUID, parts: &[&[u8]]) -> Layout {
+        let mut hasher = Sha1::new();
+        hasher.update(&namespace.0);
+        for part in parts {
+            hasher.update(part);
+        }
+        Self::hashed(&hasher.digest().bytes(), Version::SHA1)
+    }
+
+    /// Generate a UUID uses SHA1 over a name streamed from `reader` in
+    /// chunks, without reading it into memory first.
+    #[cfg(all(feature = "hsha1", feature = "std"))]
+    pub fn v5_reader<R: io::Read>(namespace: UUID, mut reader: R) -> io::Result<Layout> {
+        let mut hasher = Sha1::new();
+        hasher.update(&namespace.0);
+        let mut buf = [0u8; 8192];
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => hasher.update(&buf[..n]),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(Self::hashed(&hasher.digest().bytes(), Version::SHA1))
+    }
+
+    /// Generate a UUID uses SHA1 for each name under one namespace, the
+    /// hasher state after the namespace bytes is reused for every name.
+    #[cfg(all(feature = "hsha1", feature = "alloc"))]
+    pub fn v5_batch(namespace: UUID, names: &[&[u8]]) -> Vec<Layout> {
+        let generator = NameGenerator::new(namespace);
+        names.iter().map(|name| generator.layout(name)).collect()
+    }
+
+    /// Generate a UUID uses SHA1 over a directory tree given as (path, content
+    /// hash) pairs, entries are sorted by path first so input order is irrelevant.
+    #[cfg(all(feature = "hsha1", feature = "alloc"))]
+    pub fn v5_from_tree(namespace: UUID, entries: &[(String, [u8; 16])]) -> Layout {
+        let mut sorted: Vec<&(String, [u8; 16])> = entries.iter().collect();
+        sorted.sort();
+
+        let mut hasher = Sha1::new();
+        hasher.update(&namespace.0);
+        for (path, hash) in sorted {
+            // Length prefix keeps a path from running into the bytes of its hash.
+            hasher.update(&(path.len() as u64).to_be_bytes());
+            hasher.update(path.as_bytes());
+            hasher.update(hash);
+        }
+        Self::hashed(&hasher.digest().bytes(), Version::SHA1)
+    }
+
+    /// Generate a UUID uses SHA1 over a label and arbitrary data, such as the
+    /// bytes of a commit hash, the label keeps different kinds of data apart.
+    #[cfg(feature = "hsha1")]
+    pub fn v5_from_bytes_labeled(label: &str, data: &[u8], namespace: UUID) -> Layout {
+        Self::sha1(&[
+            &namespace.0,
+            &(label.len() as u64).to_be_bytes(),
+            label.as_bytes(),
+            data,
+        ])
+    }
+
+    /// Generate a UUID by hashing a name under several namespaces uses SHA1,
+    /// each namespace scopes the previous one in order before the name is hashed.
+    /// No namespaces at all hash the name under the nil namespace.
+    #[cfg(feature = "hsha1")]
+    pub fn v5_multi_ns(name: &[u8], namespaces: &[UUID]) -> Layout {
+        let mut scope = namespaces.first().copied().unwrap_or(UUID::nil());
+        for namespace in namespaces.iter().skip(1) {
+            scope = Self::sha1(&[&scope.0, &namespace.0]).to_uuid();
+        }
+        Self::sha1(&[&scope.0, name])
+    }
+
+    /// Derive a related UUID uses SHA1 over this UUID and a discriminator,
+    /// the same pair always yields the same UUID.
+    #[cfg(feature = "hsha1")]
+    pub fn with_discriminator(&self, disc: u32) -> Layout {
+        Self::sha1(&[&self.0, &disc.to_be_bytes()])
+    }
+
+    /// Generate a UUID uses SHA1 from the string form of a socket address
+    /// under `NAMESPACE_URL`, stable for the same address.
+    #[cfg(feature = "hsha1")]
+    pub fn v5_from_socket_addr(addr: SocketAddr) -> Layout {
+        let mut name = AddrBuf::default();
+        write!(name, "{}", addr).expect("socket address fits the buffer");
+        Self::sha1(&[&UUID::NAMESPACE_URL.0, name.as_bytes()])
+    }
+
+    /// Returns the MD5 hash of a namespace identifier and name, before the
+    /// version and variant bits are stamped.
+    #[cfg(feature = "hmd5")]
+    pub fn raw_md5(name: &[u8], namespace: UUID) -> [u8; 16] {
+        let mut ctx = md5::Context::new();
+        ctx.consume(namespace.0);
+        ctx.consume(name);
+        ctx.compute().0
+    }
+
+    /// Returns the first 16 bytes of the SHA1 hash of a namespace identifier
+    /// and name, before the version and variant bits are stamped.
+    #[cfg(feature = "hsha1")]
+    pub fn raw_sha1_16(name: &[u8], namespace: UUID) -> [u8; 16] {
+        let hash = Self::sha1_digest(&[&namespace.0, name]);
+        let mut raw = [0u8; 16];
+        raw.copy_from_slice(&hash[..16]);
+        raw
+    }
+
+    #[cfg(feature = "hsha1")]
+    fn sha1_digest(parts: &[&[u8]]) -> [u8; 20] {
+        let mut hasher = Sha1::new();
+        for part in parts {
+            hasher.update(part);
         }
+        hasher.digest().bytes()
     }
 
-    /// Generate a UUID by hashing a namespace identifier and name uses SHA1.
     #[cfg(feature = "hsha1")]
-    pub fn v5(any: &str, namespace: UUID) -> Layout {
-        let hash = Sha1::from(Self::data(any, namespace)).digest().bytes();
+    fn sha1(parts: &[&[u8]]) -> Layout {
+        Self::hashed(&Self::sha1_digest(parts), Version::SHA1)
+    }
+
+    /// Generate a custom UUID uses SHA-256 over a namespace identifier and
+    /// name, the first 16 bytes of the hash carry version 8.
+    #[cfg(feature = "hsha256")]
+    pub fn v8_sha256(name: &[u8], namespace: UUID) -> Layout {
+        let hash = Sha256::new()
+            .chain_update(namespace.0)
+            .chain_update(name)
+            .finalize();
+        Self::hashed(&hash, Version::CUSTOM)
+    }
+
+    /// Stamp the version and variant over the first 16 bytes of a hash.
+    fn hashed(hash: &[u8], v: Version) -> Layout {
         Layout {
             field_low: ((hash[0] as u32) << 24)
                 | (hash[1] as u32) << 16
@@ -36,15 +220,78 @@ impl UUID {
                 | hash[3] as u32,
             field_mid: (hash[4] as u16) << 8 | (hash[5] as u16),
             field_high_and_version: ((hash[6] as u16) << 8 | (hash[7] as u16)) & 0xfff
-                | (Version::SHA1 as u16) << 12,
-            clock_seq_high_and_reserved: (hash[8] & 0xf) | (Variant::RFC as u8) << 4,
-            clock_seq_low: hash[9] as u8,
+                | (v as u16) << 12,
+            clock_seq_high_and_reserved: Variant::RFC.stamp(hash[8]),
+            clock_seq_low: hash[9],
             node: [hash[10], hash[11], hash[12], hash[13], hash[14], hash[15]],
         }
     }
+}
+
+/// Generates version 5 UUIDs under one namespace, hashing the namespace
+/// bytes once and reusing that SHA1 state for every name.
+#[cfg(feature = "hsha1")]
+#[derive(Clone)]
+pub struct NameGenerator {
+    prefix: Sha1,
+}
+
+#[cfg(feature = "hsha1")]
+impl NameGenerator {
+    /// Create a generator for names under `namespace`.
+    pub fn new(namespace: UUID) -> Self {
+        let mut prefix = Sha1::new();
+        prefix.update(&namespace.0);
+        Self { prefix }
+    }
+
+    /// Generate the UUID of `name`, the same as [`UUID::v5`] under this namespace.
+    pub fn generate(&self, name: &[u8]) -> UUID {
+        self.layout(name).to_uuid()
+    }
+
+    fn layout(&self, name: &[u8]) -> Layout {
+        let mut hasher = self.prefix.clone();
+        hasher.update(name);
+        UUID::hashed(&hasher.digest().bytes(), Version::SHA1)
+    }
+}
+
+/// Stack buffer for the string form of a socket address, the longest being
+/// a scoped IPv6 address such as `[ffff:...:ffff%4294967295]:65535`.
+#[cfg(feature = "hsha1")]
+struct AddrBuf {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+#[cfg(feature = "hsha1")]
+impl Default for AddrBuf {
+    fn default() -> Self {
+        Self {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+}
+
+#[cfg(feature = "hsha1")]
+impl AddrBuf {
+    fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
 
-    fn data(any: &str, namespace: UUID) -> String {
-        format!("{}", namespace) + any
+#[cfg(feature = "hsha1")]
+impl Write for AddrBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
     }
 }
 
@@ -52,7 +299,7 @@ impl UUID {
 #[macro_export]
 macro_rules! v3 {
     ($any:expr, $namespace:expr) => {
-        format!("{}", $crate::UUID::v3($any, $namespace).as_bytes())
+        format!("{}", $crate::UUID::v3($any, $namespace))
     };
 }
 
@@ -60,7 +307,23 @@ macro_rules! v3 {
 #[macro_export]
 macro_rules! v5 {
     ($any:expr, $namespace:expr) => {
-        format!("{}", $crate::UUID::v5($any, $namespace).as_bytes())
+        format!("{}", $crate::UUID::v5($any, $namespace))
+    };
+}
+
+/// Creates a `UUID` version-3, the typed form of `v3!`.
+#[macro_export]
+macro_rules! v3_uuid {
+    ($any:expr, $namespace:expr) => {
+        $crate::UUID::v3($any, $namespace)
+    };
+}
+
+/// Creates a `UUID` version-5, the typed form of `v5!`.
+#[macro_export]
+macro_rules! v5_uuid {
+    ($any:expr, $namespace:expr) => {
+        $crate::UUID::v5($any, $namespace)
     };
 }
 
@@ -77,13 +340,43 @@ mod tests {
 
     const TEST_STRINGS: [&str; 3] = ["test", "example", "sample"];
 
+    // Borrowing is what is under test here.
+    #[allow(clippy::needless_borrows_for_generic_args)]
+    #[test]
+    fn test_namespace_by_ref() {
+        struct Scope {
+            namespace: UUID,
+        }
+        let scope = Scope {
+            namespace: UUID::NAMESPACE_URL,
+        };
+        for name in TEST_STRINGS {
+            #[cfg(feature = "hmd5")]
+            assert_eq!(
+                UUID::v3(name, &scope.namespace),
+                UUID::v3(name, scope.namespace)
+            );
+            #[cfg(feature = "hsha1")]
+            assert_eq!(
+                UUID::v5(name, &scope.namespace),
+                UUID::v5(name, scope.namespace)
+            );
+            #[cfg(feature = "hsha1")]
+            assert_eq!(
+                UUID::v5_checked(name, &scope.namespace),
+                UUID::v5_checked(name, scope.namespace)
+            );
+        }
+    }
+
     #[cfg(feature = "hmd5")]
     #[test]
     fn test_v3_basic() {
         for &namespace in &TEST_NAMESPACES {
             let uuid = UUID::v3("any", namespace);
-            assert_eq!(uuid.get_version(), Some(Version::MD5));
-            assert_eq!(uuid.get_variant(), Some(Variant::RFC));
+            assert_eq!(uuid.version(), Some(Version::MD5));
+            assert_eq!(uuid.variant(), Some(Variant::RFC));
+            assert_eq!(uuid, UUID::v3_layout("any", namespace).to_uuid());
         }
     }
 
@@ -99,13 +392,154 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "hmd5")]
+    #[test]
+    fn test_v3_rfc_vector() {
+        assert_eq!(
+            format!("{}", UUID::v3("python.org", UUID::NAMESPACE_DNS)),
+            "6fa459ea-ee8a-3ca4-894e-db77e160355e"
+        );
+        assert_eq!(
+            UUID::v3(b"python.org", UUID::NAMESPACE_DNS),
+            UUID::v3("python.org", UUID::NAMESPACE_DNS)
+        );
+    }
+
+    #[cfg(feature = "hsha1")]
+    #[test]
+    fn test_v5_rfc_vector() {
+        assert_eq!(
+            format!("{}", UUID::v5("python.org", UUID::NAMESPACE_DNS)),
+            "886313e1-3b8a-5372-9b90-0c9aee199e5d"
+        );
+        assert_eq!(
+            UUID::v5(String::from("python.org"), UUID::NAMESPACE_DNS),
+            UUID::v5("python.org", UUID::NAMESPACE_DNS)
+        );
+    }
+
+    // Matches Python's `uuid3` and `uuid5` of `NAMESPACE_DNS` and "".
+    #[test]
+    fn test_empty_name_vector() {
+        #[cfg(feature = "hmd5")]
+        assert_eq!(
+            UUID::v3(b"", UUID::NAMESPACE_DNS).to_string(),
+            "c87ee674-4ddc-3efe-a74e-dfe25da5d7b3"
+        );
+        #[cfg(feature = "hsha1")]
+        assert_eq!(
+            UUID::v5(b"", UUID::NAMESPACE_DNS).to_string(),
+            "4ebd0208-8328-5d69-8c44-ec50939c0967"
+        );
+        #[cfg(feature = "hsha1")]
+        assert_eq!(
+            UUID::v5("", UUID::NAMESPACE_DNS),
+            UUID::v5_parts(UUID::NAMESPACE_DNS, &[]).to_uuid()
+        );
+    }
+
     #[cfg(feature = "hsha1")]
     #[test]
     fn test_v5_basic() {
         for &namespace in &TEST_NAMESPACES {
             let uuid = UUID::v5("any", namespace);
-            assert_eq!(uuid.get_version(), Some(Version::SHA1));
-            assert_eq!(uuid.get_variant(), Some(Variant::RFC));
+            assert_eq!(uuid.version(), Some(Version::SHA1));
+            assert_eq!(uuid.variant(), Some(Variant::RFC));
+            assert_eq!(uuid, UUID::v5_layout("any", namespace).to_uuid());
+        }
+    }
+
+    #[cfg(all(feature = "hmd5", feature = "hsha1"))]
+    #[test]
+    fn test_typed_macros() {
+        let uuid: UUID = crate::v5_uuid!("python.org", UUID::NAMESPACE_DNS);
+        assert_eq!(
+            uuid.to_string(),
+            crate::v5!("python.org", UUID::NAMESPACE_DNS)
+        );
+        let uuid: UUID = crate::v3_uuid!("python.org", UUID::NAMESPACE_DNS);
+        assert_eq!(
+            uuid.to_string(),
+            crate::v3!("python.org", UUID::NAMESPACE_DNS)
+        );
+    }
+
+    #[cfg(feature = "hsha1")]
+    #[test]
+    fn test_v5_checked() {
+        assert_eq!(
+            UUID::v5_checked("python.org", UUID::nil()),
+            Err(Error::NilNamespace)
+        );
+        assert_eq!(
+            UUID::v5_checked("python.org", UUID::NAMESPACE_DNS),
+            Ok(UUID::v5_layout("python.org", UUID::NAMESPACE_DNS))
+        );
+        assert!(UUID::v5("python.org", UUID::nil()).is_valid_rfc());
+    }
+
+    #[cfg(feature = "hmd5")]
+    #[test]
+    fn test_v3_checked() {
+        assert_eq!(
+            UUID::v3_checked("python.org", UUID::nil()),
+            Err(Error::NilNamespace)
+        );
+        assert_eq!(
+            UUID::v3_checked("python.org", UUID::NAMESPACE_DNS),
+            Ok(UUID::v3_layout("python.org", UUID::NAMESPACE_DNS))
+        );
+    }
+
+    #[cfg(feature = "hsha1")]
+    #[test]
+    fn test_v5_parts() {
+        for &namespace in &TEST_NAMESPACES {
+            assert_eq!(
+                UUID::v5_parts(namespace, &[b"a", b"b"]).to_uuid(),
+                UUID::v5(b"ab", namespace)
+            );
+            assert_eq!(
+                UUID::v5_parts(namespace, &[b"python", b"", b".org"]).to_uuid(),
+                UUID::v5("python.org", namespace)
+            );
+            assert_eq!(
+                UUID::v5_parts(namespace, &[]).to_uuid(),
+                UUID::v5(b"", namespace)
+            );
+        }
+    }
+
+    #[cfg(feature = "hsha1")]
+    #[test]
+    fn test_name_generator() {
+        for &namespace in &TEST_NAMESPACES {
+            let generator = NameGenerator::new(namespace);
+            assert_eq!(generator.generate(b"x"), UUID::v5(b"x", namespace));
+            for &test_str in &TEST_STRINGS {
+                assert_eq!(
+                    generator.generate(test_str.as_bytes()),
+                    UUID::v5(test_str, namespace)
+                );
+            }
+        }
+    }
+
+    #[cfg(all(feature = "hsha1", feature = "std"))]
+    #[test]
+    fn test_v5_reader() {
+        use std::io::Cursor;
+
+        let large: Vec<u8> = (0..100_000u32).map(|i| i as u8).collect();
+        for name in [&b""[..], b"python.org", &large] {
+            for &namespace in &TEST_NAMESPACES {
+                assert_eq!(
+                    UUID::v5_reader(namespace, Cursor::new(name))
+                        .unwrap()
+                        .to_uuid(),
+                    UUID::v5(name, namespace)
+                );
+            }
         }
     }
 
@@ -132,4 +566,161 @@ mod tests {
             }
         }
     }
+
+    #[cfg(all(feature = "hsha1", feature = "alloc"))]
+    #[test]
+    fn test_v5_batch() {
+        let names: Vec<&[u8]> = TEST_STRINGS.iter().map(|s| s.as_bytes()).collect();
+        for &namespace in &TEST_NAMESPACES {
+            let batch = UUID::v5_batch(namespace, &names);
+            assert_eq!(batch.len(), names.len());
+            for (uuid, name) in batch.iter().zip(names.iter()) {
+                assert_eq!(*uuid, UUID::v5_layout(name, namespace));
+            }
+        }
+        assert!(UUID::v5_batch(UUID::NAMESPACE_DNS, &[]).is_empty());
+    }
+
+    #[cfg(all(feature = "hsha1", feature = "alloc"))]
+    #[test]
+    fn test_v5_from_tree() {
+        let entries = vec![
+            (String::from("src/lib.rs"), [1; 16]),
+            (String::from("src/name.rs"), [2; 16]),
+            (String::from("Cargo.toml"), [3; 16]),
+        ];
+        let uuid = UUID::v5_from_tree(UUID::NAMESPACE_URL, &entries);
+        assert_eq!(uuid.get_version(), Some(Version::SHA1));
+
+        let mut reversed = entries.clone();
+        reversed.reverse();
+        assert_eq!(uuid, UUID::v5_from_tree(UUID::NAMESPACE_URL, &reversed));
+
+        let mut changed = entries.clone();
+        changed[1].1[15] ^= 1;
+        assert_ne!(uuid, UUID::v5_from_tree(UUID::NAMESPACE_URL, &changed));
+
+        let mut renamed = entries.clone();
+        renamed[0].0 = String::from("src/main.rs");
+        assert_ne!(uuid, UUID::v5_from_tree(UUID::NAMESPACE_URL, &renamed));
+
+        assert_ne!(uuid, UUID::v5_from_tree(UUID::NAMESPACE_DNS, &entries));
+        assert_ne!(uuid, UUID::v5_from_tree(UUID::NAMESPACE_URL, &entries[..2]));
+    }
+
+    #[cfg(feature = "hsha1")]
+    #[test]
+    fn test_v5_from_bytes_labeled() {
+        let commit = [0xab; 20];
+        let uuid = UUID::v5_from_bytes_labeled("git-commit", &commit, UUID::NAMESPACE_URL);
+        assert_eq!(uuid.get_version(), Some(Version::SHA1));
+        assert_eq!(
+            uuid,
+            UUID::v5_from_bytes_labeled("git-commit", &commit, UUID::NAMESPACE_URL)
+        );
+        assert_ne!(
+            uuid,
+            UUID::v5_from_bytes_labeled("git-tree", &commit, UUID::NAMESPACE_URL)
+        );
+        assert_ne!(
+            uuid,
+            UUID::v5_from_bytes_labeled("git-commit", &commit[1..], UUID::NAMESPACE_URL)
+        );
+        assert_ne!(
+            UUID::v5_from_bytes_labeled("a", b"bc", UUID::NAMESPACE_URL),
+            UUID::v5_from_bytes_labeled("ab", b"c", UUID::NAMESPACE_URL)
+        );
+    }
+
+    #[cfg(feature = "hsha1")]
+    #[test]
+    fn test_v5_multi_ns() {
+        let tenant = UUID::NAMESPACE_DNS;
+        let resource = UUID::NAMESPACE_URL;
+
+        let uuid = UUID::v5_multi_ns(b"name", &[tenant, resource]);
+        assert_eq!(uuid.get_version(), Some(Version::SHA1));
+        assert_eq!(uuid, UUID::v5_multi_ns(b"name", &[tenant, resource]));
+        assert_ne!(uuid, UUID::v5_multi_ns(b"name", &[resource, tenant]));
+        assert_ne!(uuid, UUID::v5_multi_ns(b"name", &[tenant]));
+        assert_eq!(
+            UUID::v5_multi_ns(b"name", &[tenant]),
+            UUID::v5_layout(b"name", tenant)
+        );
+        assert_eq!(
+            UUID::v5_multi_ns(b"name", &[]),
+            UUID::v5_layout(b"name", UUID::nil())
+        );
+    }
+
+    #[cfg(feature = "hsha1")]
+    #[test]
+    fn test_v5_from_socket_addr() {
+        let v4: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+        let v6: SocketAddr = "[::1]:8080".parse().unwrap();
+
+        let uuid = UUID::v5_from_socket_addr(v4);
+        assert_eq!(uuid.get_version(), Some(Version::SHA1));
+        assert_eq!(uuid, UUID::v5_from_socket_addr(v4));
+        assert_ne!(uuid, UUID::v5_from_socket_addr(v6));
+        assert_ne!(
+            uuid,
+            UUID::v5_from_socket_addr("127.0.0.1:8081".parse().unwrap())
+        );
+    }
+
+    #[cfg(feature = "hsha256")]
+    #[test]
+    fn test_v8_sha256() {
+        for &namespace in &TEST_NAMESPACES {
+            for &test_str in &TEST_STRINGS {
+                let uuid = UUID::v8_sha256(test_str.as_bytes(), namespace);
+                assert_eq!(uuid.get_version(), Some(Version::CUSTOM));
+                assert_eq!(uuid.get_variant(), Some(Variant::RFC));
+                assert_eq!(uuid, UUID::v8_sha256(test_str.as_bytes(), namespace));
+                #[cfg(feature = "hsha1")]
+                assert_ne!(uuid.to_uuid(), UUID::v5(test_str, namespace));
+            }
+        }
+        assert_ne!(
+            UUID::v8_sha256(b"name", UUID::NAMESPACE_DNS),
+            UUID::v8_sha256(b"name", UUID::NAMESPACE_URL)
+        );
+    }
+
+    #[cfg(feature = "hmd5")]
+    #[test]
+    fn test_raw_md5() {
+        assert_eq!(
+            UUID::raw_md5(b"python.org", UUID::NAMESPACE_DNS),
+            [
+                0x6f, 0xa4, 0x59, 0xea, 0xee, 0x8a, 0x1c, 0xa4, 0x49, 0x4e, 0xdb, 0x77, 0xe1, 0x60,
+                0x35, 0x5e,
+            ]
+        );
+    }
+
+    #[cfg(feature = "hsha1")]
+    #[test]
+    fn test_raw_sha1_16() {
+        assert_eq!(
+            UUID::raw_sha1_16(b"python.org", UUID::NAMESPACE_DNS),
+            [
+                0x88, 0x63, 0x13, 0xe1, 0x3b, 0x8a, 0x53, 0x72, 0x5b, 0x90, 0x0c, 0x9a, 0xee, 0x19,
+                0x9e, 0x5d,
+            ]
+        );
+    }
+
+    #[cfg(feature = "hsha1")]
+    #[test]
+    fn test_with_discriminator() {
+        for &namespace in &TEST_NAMESPACES {
+            let first = namespace.with_discriminator(1);
+            assert_eq!(first.get_version(), Some(Version::SHA1));
+            assert_eq!(first, namespace.with_discriminator(1));
+            assert_ne!(first, namespace.with_discriminator(2));
+            assert_ne!(first.to_uuid(), namespace);
+        }
+    }
 }
@@ -0,0 +1,595 @@
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{Error, UUID};
+
+/// Length of the canonical hyphenated form.
+const HYPHENATED_LEN: usize = 36;
+
+/// Length of the hyphenless form.
+const SIMPLE_LEN: usize = 32;
+
+/// Offsets of the hyphens in the canonical hyphenated form.
+const HYPHENS: [usize; 4] = [8, 13, 18, 23];
+
+/// Prefix of the URN form.
+const URN_PREFIX: &str = "urn:uuid:";
+
+impl UUID {
+    /// Parse a UUID from its canonical hyphenated form or the 32-digit
+    /// hyphenless form, optionally prefixed with `urn:uuid:` or wrapped in
+    /// braces. Both upper and lower case hex digits are accepted, error
+    /// indices point into the whole input. Decodes straight from the input
+    /// bytes without allocating.
+    pub fn parse_str(s: &str) -> Result<UUID, Error> {
+        let (src, offset) = if let Some(rest) = s.strip_prefix(URN_PREFIX) {
+            (rest, URN_PREFIX.len())
+        } else if let Some(rest) = s.strip_prefix('{') {
+            (rest.strip_suffix('}').ok_or(Error::UnbalancedBraces)?, 1)
+        } else if s.ends_with('}') {
+            return Err(Error::UnbalancedBraces);
+        } else {
+            (s, 0)
+        };
+        decode(src.as_bytes(), offset)
+    }
+
+    /// Parse with the strictness picked by `mode`, see [`ParseMode`].
+    pub fn parse_with_mode(s: &str, mode: ParseMode) -> Result<UUID, Error> {
+        match mode {
+            ParseMode::Strict if s.len() != HYPHENATED_LEN => Err(Error::InvalidLength(s.len())),
+            ParseMode::Strict => decode(s.as_bytes(), 0),
+            ParseMode::Lenient => Self::parse_str(s),
+        }
+    }
+}
+
+/// How much of the textual forms a parse accepts.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ParseMode {
+    /// Only the canonical 36-character hyphenated form, in either case.
+    Strict,
+    /// Every form accepted by [`UUID::parse_str`]: hyphenated, hyphenless,
+    /// `urn:uuid:` prefixed or wrapped in braces.
+    Lenient,
+}
+
+/// Decode the hyphenated or hyphenless form, errors report `offset + i`.
+fn decode(src: &[u8], offset: usize) -> Result<UUID, Error> {
+    let mut bytes = [0u8; 16];
+    let mut n = 0;
+    let mut i = 0;
+    match src.len() {
+        HYPHENATED_LEN => {
+            while i < HYPHENATED_LEN {
+                if HYPHENS.contains(&i) {
+                    if src[i] != b'-' {
+                        return Err(Error::InvalidGroup(offset + i));
+                    }
+                    i += 1;
+                    continue;
+                }
+                bytes[n] = byte(src, i, offset)?;
+                n += 1;
+                i += 2;
+            }
+        }
+        SIMPLE_LEN => {
+            while i < SIMPLE_LEN {
+                bytes[n] = byte(src, i, offset)?;
+                n += 1;
+                i += 2;
+            }
+        }
+        len => return Err(Error::InvalidLength(len)),
+    }
+
+    Ok(UUID(bytes))
+}
+
+impl UUID {
+    /// Parse a UUID pasted from messy input such as logs: surrounding
+    /// whitespace, quotes and trailing `,`, `;` or `.` are dropped and
+    /// hyphens may sit anywhere between the 32 hex digits. Use
+    /// [`UUID::parse_str`] where input must be well-formed.
+    pub fn parse_lenient(s: &str) -> Result<UUID, Error> {
+        let src = s
+            .trim()
+            .trim_end_matches([',', ';', '.'])
+            .trim_matches(['"', '\'', '`'])
+            .trim();
+        // `src` is a subslice of `s`, so errors can still point into the whole input.
+        let offset = src.as_ptr() as usize - s.as_ptr() as usize;
+        if src.starts_with(URN_PREFIX) || src.starts_with('{') || src.ends_with('}') {
+            return Self::parse_str(src).map_err(|e| match e {
+                Error::InvalidCharacter(i) => Error::InvalidCharacter(offset + i),
+                Error::InvalidGroup(i) => Error::InvalidGroup(offset + i),
+                e => e,
+            });
+        }
+
+        let mut bytes = [0u8; 16];
+        let mut n = 0;
+        for (i, c) in src.bytes().enumerate() {
+            if c == b'-' {
+                continue;
+            }
+            let v = hex(c).ok_or(Error::InvalidCharacter(offset + i))?;
+            if n == SIMPLE_LEN {
+                return Err(Error::InvalidLength(src.len()));
+            }
+            bytes[n / 2] |= v << (4 * (1 - n % 2));
+            n += 1;
+        }
+        if n != SIMPLE_LEN {
+            return Err(Error::InvalidLength(src.len()));
+        }
+        Ok(UUID(bytes))
+    }
+
+    /// Parse every whitespace separated token of `input`, such as a file
+    /// with one UUID per line, keeping one result per token in order.
+    #[cfg(feature = "alloc")]
+    pub fn parse_many(input: &str) -> Vec<Result<UUID, Error>> {
+        input.split_whitespace().map(UUID::parse_str).collect()
+    }
+
+    /// Parse `tokens` into the caller's `out` without allocating, returns how
+    /// many were written, at most the shorter of both. Stops at the first
+    /// token that fails with `Error::InvalidToken` holding its index.
+    pub fn parse_into(tokens: &[&str], out: &mut [UUID]) -> Result<usize, Error> {
+        let mut n = 0;
+        for (token, slot) in tokens.iter().zip(out.iter_mut()) {
+            *slot = Self::parse_str(token).map_err(|_| Error::InvalidToken(n))?;
+            n += 1;
+        }
+        Ok(n)
+    }
+}
+
+impl FromStr for UUID {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s)
+    }
+}
+
+impl TryFrom<&str> for UUID {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse_str(s)
+    }
+}
+
+/// Compares with a string in any form accepted by [`UUID::parse_str`],
+/// a string which fails to parse is never equal.
+impl PartialEq<str> for UUID {
+    fn eq(&self, other: &str) -> bool {
+        Self::parse_str(other).is_ok_and(|uuid| uuid == *self)
+    }
+}
+
+impl PartialEq<&str> for UUID {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+/// Decode the two hex digits at `i`, errors report `offset + i`.
+fn byte(src: &[u8], i: usize, offset: usize) -> Result<u8, Error> {
+    let hi = hex(src[i]).ok_or(Error::InvalidCharacter(offset + i))?;
+    let lo = hex(src[i + 1]).ok_or(Error::InvalidCharacter(offset + i + 1))?;
+    Ok(hi << 4 | lo)
+}
+
+/// Marks a byte which is not a hex digit in `HEX_TABLE`.
+const INVALID: u8 = 0xff;
+
+/// Value of every ASCII hex digit, built at compile time.
+const HEX_TABLE: [u8; 256] = {
+    let mut table = [INVALID; 256];
+    let mut i = 0;
+    while i < 10 {
+        table[b'0' as usize + i] = i as u8;
+        i += 1;
+    }
+    let mut i = 0;
+    while i < 6 {
+        table[b'a' as usize + i] = 10 + i as u8;
+        table[b'A' as usize + i] = 10 + i as u8;
+        i += 1;
+    }
+    table
+};
+
+fn hex(c: u8) -> Option<u8> {
+    match HEX_TABLE[c as usize] {
+        INVALID => None,
+        v => Some(v),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_with_mode() {
+        let hyphenated = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
+        for s in [hyphenated, "6BA7B810-9DAD-11D1-80B4-00C04FD430C8"] {
+            assert_eq!(
+                UUID::parse_with_mode(s, ParseMode::Strict),
+                Ok(UUID::NAMESPACE_DNS)
+            );
+            assert_eq!(
+                UUID::parse_with_mode(s, ParseMode::Lenient),
+                Ok(UUID::NAMESPACE_DNS)
+            );
+        }
+
+        for s in [
+            "6ba7b8109dad11d180b400c04fd430c8",
+            "urn:uuid:6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "{6ba7b810-9dad-11d1-80b4-00c04fd430c8}",
+        ] {
+            assert_eq!(
+                UUID::parse_with_mode(s, ParseMode::Strict),
+                Err(Error::InvalidLength(s.len()))
+            );
+            assert_eq!(
+                UUID::parse_with_mode(s, ParseMode::Lenient),
+                Ok(UUID::NAMESPACE_DNS)
+            );
+        }
+
+        // 36 bytes long but not the hyphenated layout.
+        let s = "{6ba7b810-9dad-11d1-80b4-00c04fd430c";
+        assert_eq!(
+            UUID::parse_with_mode(s, ParseMode::Strict),
+            Err(Error::InvalidCharacter(0))
+        );
+        assert_eq!(
+            UUID::parse_with_mode(s, ParseMode::Lenient),
+            Err(Error::UnbalancedBraces)
+        );
+        let s = "6ba7b810-9dad-11d1-80b4_00c04fd430c8";
+        assert_eq!(
+            UUID::parse_with_mode(s, ParseMode::Strict),
+            Err(Error::InvalidGroup(23))
+        );
+        assert_eq!(
+            UUID::parse_with_mode(s, ParseMode::Lenient),
+            Err(Error::InvalidGroup(23))
+        );
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        for namespace in [
+            UUID::NAMESPACE_DNS,
+            UUID::NAMESPACE_OID,
+            UUID::NAMESPACE_URL,
+            UUID::NAMESPACE_X500,
+        ] {
+            let s = format!("{}", namespace);
+            assert_eq!(s.parse::<UUID>(), Ok(namespace));
+            assert_eq!(UUID::parse_str(&s.to_uppercase()), Ok(namespace));
+            assert_eq!(UUID::parse_str(&format!("urn:uuid:{}", s)), Ok(namespace));
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            UUID::parse_str("6ba7b810-9dad-11d1-80b4-00c04fd430c"),
+            Err(Error::InvalidLength(35))
+        );
+        assert_eq!(
+            UUID::parse_str("6ba7b810-9dad-11d1-80b4-00c04fd430cg"),
+            Err(Error::InvalidCharacter(35))
+        );
+        assert_eq!(
+            UUID::parse_str("6ba7b8109-dad-11d1-80b4-00c04fd430c8"),
+            Err(Error::InvalidGroup(8))
+        );
+        assert_eq!(UUID::parse_str(""), Err(Error::InvalidLength(0)));
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        assert_eq!(
+            UUID::try_from("6ba7b810-9dad-11d1-80b4-00c04fd430c8"),
+            Ok(UUID::NAMESPACE_DNS)
+        );
+        assert_eq!(
+            UUID::try_from("6ba7b810-9dad-11d1-80b4-00c04fd430cg"),
+            Err(Error::InvalidCharacter(35))
+        );
+        let parsed: Result<UUID, Error> = "6ba7b810".try_into();
+        assert_eq!(parsed, "6ba7b810".parse::<UUID>());
+    }
+
+    #[test]
+    fn test_eq_str() {
+        let uuid = UUID::NAMESPACE_DNS;
+        assert_eq!(uuid, "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+        assert_eq!(uuid, *"6BA7B8109DAD11D180B400C04FD430C8");
+        assert_ne!(uuid, "6ba7b811-9dad-11d1-80b4-00c04fd430c8");
+        assert_ne!(uuid, "6ba7b810-9dad-11d1-80b4");
+        assert_ne!(uuid, "not a uuid");
+        assert_ne!(UUID::nil(), "");
+    }
+
+    #[test]
+    fn test_parse_garbage() {
+        // xorshift, so every run feeds the same inputs.
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        let pieces = [
+            "0",
+            "a",
+            "F",
+            "g",
+            "-",
+            "{",
+            "}",
+            "\"",
+            " ",
+            ",",
+            "é",
+            "€",
+            "😀",
+            "\0",
+            "urn:uuid:",
+        ];
+        let valid = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
+
+        for _ in 0..10_000 {
+            let mut input = String::new();
+            if next() % 2 == 0 {
+                // A valid UUID with some characters replaced.
+                let at = next() as usize % valid.len();
+                input.push_str(&valid[..at]);
+                input.push_str(pieces[next() as usize % pieces.len()]);
+                input.push_str(&valid[(at + 1).min(valid.len())..]);
+            } else {
+                for _ in 0..next() % 48 {
+                    input.push_str(pieces[next() as usize % pieces.len()]);
+                }
+            }
+            let results = [
+                UUID::parse_str(&input),
+                UUID::parse_lenient(&input),
+                UUID::from_base32(&input),
+                UUID::from_ascii85(&input),
+            ];
+            for uuid in results.into_iter().flatten() {
+                assert_eq!(uuid.to_string().parse(), Ok(uuid));
+            }
+        }
+
+        for input in ["", "-", "{", "}", "{}", "urn:uuid:", "é", &"é".repeat(18)] {
+            assert!(UUID::parse_str(input).is_err());
+            assert!(UUID::parse_lenient(input).is_err());
+        }
+        assert_eq!(
+            UUID::parse_str("6ba7b810é9dad-11d1-80b4-00c04fd430c"),
+            Err(Error::InvalidGroup(8))
+        );
+    }
+
+    #[test]
+    fn test_parse_boxed_error() {
+        fn parse(s: &str) -> Result<UUID, Box<dyn std::error::Error>> {
+            Ok(s.parse::<UUID>()?)
+        }
+
+        assert_eq!(
+            parse("6ba7b810-9dad-11d1-80b4-00c04fd430c8").unwrap(),
+            UUID::NAMESPACE_DNS
+        );
+        for (input, error) in [
+            ("6ba7b810", Error::InvalidLength(8)),
+            (
+                "6ba7b810-9dad-11d1-80b4-00c04fd430cx",
+                Error::InvalidCharacter(35),
+            ),
+            (
+                "6ba7b810-9dad-11d1-80b4_00c04fd430c8",
+                Error::InvalidGroup(23),
+            ),
+        ] {
+            let boxed = parse(input).unwrap_err();
+            assert_eq!(boxed.to_string(), error.to_string());
+            assert_eq!(boxed.downcast_ref::<Error>(), Some(&error));
+        }
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        let dns = UUID::NAMESPACE_DNS;
+        for input in [
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "  \"6ba7b810-9dad-11d1-80b4-00c04fd430c8\",\n",
+            "'6BA7B810-9DAD-11D1-80B4-00C04FD430C8';",
+            "6ba7b8109dad11d180b400c04fd430c8.",
+            "6ba7b810-9dad11d1-80b400c04fd430c8",
+            "`{6ba7b810-9dad-11d1-80b4-00c04fd430c8}`",
+            "\"urn:uuid:6ba7b810-9dad-11d1-80b4-00c04fd430c8\"",
+        ] {
+            assert_eq!(UUID::parse_lenient(input), Ok(dns), "{:?}", input);
+        }
+
+        assert_eq!(
+            UUID::parse_lenient("\"6ba7b810-9dad-11d1-80b4\","),
+            Err(Error::InvalidLength(23))
+        );
+        assert_eq!(
+            UUID::parse_lenient("6ba7b810-9dad-11d1-80b4-00c04fd430c8a"),
+            Err(Error::InvalidLength(37))
+        );
+        assert_eq!(
+            UUID::parse_lenient(" \"6ba7b810-9dad-11d1-80b4-00c04fd430cg\""),
+            Err(Error::InvalidCharacter(37))
+        );
+        let input = "  \"{6ba7b810-9dad-11d1-80b4-00c04fd430cg}\",";
+        assert_eq!(UUID::parse_lenient(input), Err(Error::InvalidCharacter(39)));
+        assert_eq!(input.as_bytes()[39], b'g');
+        let input = "  urn:uuid:6ba7b810-9dad-11d1_80b4-00c04fd430c8";
+        assert_eq!(UUID::parse_lenient(input), Err(Error::InvalidGroup(29)));
+        assert_eq!(input.as_bytes()[29], b'_');
+        assert_eq!(UUID::parse_lenient(""), Err(Error::InvalidLength(0)));
+        assert!(UUID::parse_str("\"6ba7b810-9dad-11d1-80b4-00c04fd430c8\"").is_err());
+    }
+
+    #[test]
+    fn test_parse_vectors() {
+        for (s, v) in [
+            (
+                "00000000-0000-0000-0000-000000000000",
+                0x00000000_0000_0000_0000_000000000000,
+            ),
+            (
+                "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                0xffffffff_ffff_ffff_ffff_ffffffffffff,
+            ),
+            (
+                "6ba7b811-9dad-11d1-80b4-00c04fd430c8",
+                0x6ba7b811_9dad_11d1_80b4_00c04fd430c8,
+            ),
+            (
+                "67E55044-10B1-426F-9247-BB680E5FE0C8",
+                0x67e55044_10b1_426f_9247_bb680e5fe0c8,
+            ),
+            (
+                "0123456789abcdefABCDEF0123456789",
+                0x01234567_89ab_cdef_abcd_ef0123456789,
+            ),
+        ] {
+            assert_eq!(UUID::parse_str(s), Ok(UUID::from_u128(v)), "{}", s);
+        }
+        for c in (0..=255u8).filter(|c| !c.is_ascii_hexdigit()) {
+            assert_eq!(hex(c), None);
+        }
+    }
+
+    #[test]
+    fn test_parse_str_stream() {
+        // Parses from borrowed bytes into a stack array, so a long stream
+        // of UUIDs costs no allocation per item.
+        let lines: Vec<String> = (0..10_000u128)
+            .map(|i| UUID::from_u128(i * 0x9e37_79b9_7f4a_7c15).to_string())
+            .collect();
+        for (i, line) in lines.iter().enumerate() {
+            let expected = Ok(UUID::from_u128(i as u128 * 0x9e37_79b9_7f4a_7c15));
+            assert_eq!(UUID::parse_str(line), expected);
+            assert_eq!(UUID::parse_str(&line.to_uppercase()), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_into() {
+        let tokens = [
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "{6ba7b811-9dad-11d1-80b4-00c04fd430c8}",
+            "6ba7b8129dad11d180b400c04fd430c8",
+        ];
+        let mut out = [UUID::nil(); 3];
+        assert_eq!(UUID::parse_into(&tokens, &mut out), Ok(3));
+        assert_eq!(
+            out,
+            [
+                UUID::NAMESPACE_DNS,
+                UUID::NAMESPACE_URL,
+                UUID::NAMESPACE_OID
+            ]
+        );
+
+        let mut short = [UUID::nil(); 2];
+        assert_eq!(UUID::parse_into(&tokens, &mut short), Ok(2));
+        assert_eq!(short, [UUID::NAMESPACE_DNS, UUID::NAMESPACE_URL]);
+    }
+
+    #[test]
+    fn test_parse_into_invalid() {
+        let tokens = [
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "not-a-uuid",
+            "6ba7b812-9dad-11d1-80b4-00c04fd430c8",
+        ];
+        let mut out = [UUID::nil(); 3];
+        assert_eq!(
+            UUID::parse_into(&tokens, &mut out),
+            Err(Error::InvalidToken(1))
+        );
+        assert_eq!(out[0], UUID::NAMESPACE_DNS);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_many_lines() {
+        let input = "6ba7b810-9dad-11d1-80b4-00c04fd430c8\n\
+                     \n\
+                     not-a-uuid\n\
+                     {6ba7b811-9dad-11d1-80b4-00c04fd430c8}\r\n\
+                     \t6ba7b8109dad11d180b400c04fd430cg\n";
+        assert_eq!(
+            UUID::parse_many(input),
+            [
+                Ok(UUID::NAMESPACE_DNS),
+                Err(Error::InvalidLength(10)),
+                Ok(UUID::NAMESPACE_URL),
+                Err(Error::InvalidCharacter(31)),
+            ]
+        );
+        assert!(UUID::parse_many(" \n\n").is_empty());
+    }
+
+    #[test]
+    fn test_parse_wrapped() {
+        let uuid = UUID::NAMESPACE_DNS;
+        for s in [
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "{6ba7b810-9dad-11d1-80b4-00c04fd430c8}",
+            "{6BA7B810-9DAD-11D1-80B4-00C04FD430C8}",
+            "urn:uuid:6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "6ba7b8109dad11d180b400c04fd430c8",
+            "6BA7B8109DAD11D180B400C04FD430C8",
+        ] {
+            assert_eq!(s.parse::<UUID>(), Ok(uuid), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_parse_malformed_wrappers() {
+        assert_eq!(
+            UUID::parse_str("{6ba7b810-9dad-11d1-80b4-00c04fd430c8"),
+            Err(Error::UnbalancedBraces)
+        );
+        assert_eq!(
+            UUID::parse_str("6ba7b810-9dad-11d1-80b4-00c04fd430c8}"),
+            Err(Error::UnbalancedBraces)
+        );
+        assert_eq!(
+            UUID::parse_str("urn:uuid:{6ba7b810-9dad-11d1-80b4-00c04fd430c8}"),
+            Err(Error::InvalidLength(38))
+        );
+        assert_eq!(
+            UUID::parse_str("{6ba7b810-9dad-11d1-80b4-00c04fd430cg}"),
+            Err(Error::InvalidCharacter(36))
+        );
+        assert_eq!(
+            UUID::parse_str("6ba7b8109dad11d180b400c04fd430cx"),
+            Err(Error::InvalidCharacter(31))
+        );
+    }
+}
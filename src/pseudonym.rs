@@ -0,0 +1,99 @@
+//! Keyed, reversible pseudonymization of UUIDs.
+//!
+//! The 122 bits outside the version and variant fields are permuted by an
+//! 8-round balanced Feistel network over two 61-bit halves. The round
+//! function is SHA-1 over the 32-byte key, the round number and the right
+//! half, truncated to 61 bits. The version nibble and the two most
+//! significant variant bits are kept, so the output still looks like a UUID
+//! of the same version.
+
+#![cfg(feature = "hsha1")]
+
+use sha1_smol::Sha1;
+
+use crate::UUID;
+
+const ROUNDS: u8 = 8;
+const HALF_MASK: u64 = (1 << 61) - 1;
+
+/// Bits of the UUID left untouched, the version nibble and the variant bits.
+const FIXED_MASK: u128 = 0xf << 76 | 0x3 << 62;
+
+impl UUID {
+    /// Permute this UUID under `key` so it can't be linked to the original
+    /// without the key, see [`UUID::depseudonymize`] for the inverse.
+    pub fn pseudonymize(&self, key: &[u8; 32]) -> UUID {
+        let (mut l, mut r) = split(self.as_u128());
+        for i in 0..ROUNDS {
+            (l, r) = (r, l ^ round(key, i, r));
+        }
+        UUID::from_u128(join(l, r) | (self.as_u128() & FIXED_MASK))
+    }
+
+    /// Reverse [`UUID::pseudonymize`] under the same `key`.
+    pub fn depseudonymize(&self, key: &[u8; 32]) -> UUID {
+        let (mut l, mut r) = split(self.as_u128());
+        for i in (0..ROUNDS).rev() {
+            (l, r) = (r ^ round(key, i, l), l);
+        }
+        UUID::from_u128(join(l, r) | (self.as_u128() & FIXED_MASK))
+    }
+}
+
+/// Packs the 122 variable bits and splits them into two 61-bit halves.
+fn split(v: u128) -> (u64, u64) {
+    let packed = (v >> 80) << 74 | ((v >> 64) & 0xfff) << 62 | (v & ((1 << 62) - 1));
+    ((packed >> 61) as u64, packed as u64 & HALF_MASK)
+}
+
+/// Inverse of `split`, leaving the fixed bits zero.
+fn join(l: u64, r: u64) -> u128 {
+    let packed = (l as u128) << 61 | r as u128;
+    (packed >> 74) << 80 | ((packed >> 62) & 0xfff) << 64 | (packed & ((1 << 62) - 1))
+}
+
+fn round(key: &[u8; 32], i: u8, half: u64) -> u64 {
+    let mut hasher = Sha1::new();
+    hasher.update(key);
+    hasher.update(&[i]);
+    hasher.update(&half.to_be_bytes());
+    let hash = hasher.digest().bytes();
+    u64::from_be_bytes([
+        hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], hash[6], hash[7],
+    ]) & HALF_MASK
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_NAMESPACES: [UUID; 4] = [
+        UUID::NAMESPACE_DNS,
+        UUID::NAMESPACE_OID,
+        UUID::NAMESPACE_URL,
+        UUID::NAMESPACE_X500,
+    ];
+
+    #[test]
+    fn test_pseudonymize_round_trip() {
+        let key = [7u8; 32];
+        for &uuid in &TEST_NAMESPACES {
+            let pseudonym = uuid.pseudonymize(&key);
+            assert_ne!(pseudonym, uuid);
+            assert_eq!(pseudonym.0[6] >> 4, uuid.0[6] >> 4);
+            assert_eq!(pseudonym.0[8] >> 6, uuid.0[8] >> 6);
+            assert_eq!(pseudonym.depseudonymize(&key), uuid);
+        }
+        assert_eq!(
+            UUID::max().pseudonymize(&key).depseudonymize(&key),
+            UUID::max()
+        );
+    }
+
+    #[test]
+    fn test_pseudonymize_keys_differ() {
+        for &uuid in &TEST_NAMESPACES {
+            assert_ne!(uuid.pseudonymize(&[1; 32]), uuid.pseudonymize(&[2; 32]));
+        }
+    }
+}
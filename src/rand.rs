@@ -1,38 +1,194 @@
-#![cfg(feature = "rnd")]
+#![cfg(feature = "rand")]
 
 use crate::{Layout, Variant, Version, UUID};
 
-use rand;
+use rand::{self, rngs::StdRng, Rng, RngCore, SeedableRng};
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::SystemTime;
+
+// Last version 7 state handed out, the Unix milliseconds shifted over the
+// 12-bit counter so a full counter carries into the next millisecond
+static LAST_V7: AtomicU64 = AtomicU64::new(0);
 
 impl UUID {
     /// Generate a UUID from truly random numbers.
-    #[cfg(feature = "rand")]
     pub fn v4() -> Layout {
-        let rng = rand::random::<u128>();
-        let rand = rng.to_be_bytes();
+        Self::v4_with_bytes(rand::random::<u128>().to_be_bytes())
+    }
+
+    /// Generate a random UUID together with its hyphenated string.
+    pub fn v4_with_string() -> (Layout, String) {
+        let layout = Self::v4();
+        let string = layout.to_uuid().to_string();
+        (layout, string)
+    }
+
+    /// Generate a time-ordered UUID from the Unix timestamp in milliseconds
+    /// followed by random bits.
+    pub fn v7() -> Layout {
+        let millis = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        Self::v7_with(millis, rand::random::<u128>().to_be_bytes())
+    }
+
+    /// Generate a time-ordered UUID whose 12 bits after the timestamp count
+    /// up within one millisecond (RFC 9562 section 6.2 method 1), so UUIDs of
+    /// this process strictly increase.
+    pub fn v7_monotonic() -> Layout {
+        let millis = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        let mut rand = rand::random::<u128>().to_be_bytes();
+        // A new millisecond starts the counter at a random value below half
+        // its range, leaving room to count up.
+        let start = millis << 12 | (rand[6] as u64 & 0x7) << 8 | rand[7] as u64;
+        let next = |last: u64| start.max(last + 1);
+        let last = LAST_V7
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |last| Some(next(last)))
+            .unwrap();
+        let state = next(last);
+
+        rand[6] = (state >> 8) as u8 & 0xf;
+        rand[7] = state as u8;
+        Self::v7_with(state >> 12, rand)
+    }
+
+    fn v7_with(millis: u64, rand: [u8; 16]) -> Layout {
         Layout {
-            field_low: ((rand[0] as u32) << 24)
-                | (rand[1] as u32) << 16
-                | (rand[2] as u32) << 8
-                | rand[3] as u32,
-            field_mid: (rand[4] as u16) << 8 | (rand[5] as u16),
+            field_low: (millis >> 16) as u32,
+            field_mid: (millis & 0xffff) as u16,
             field_high_and_version: ((rand[6] as u16) << 8 | (rand[7] as u16)) & 0xfff
-                | (Version::RAND as u16) << 12,
-            clock_seq_high_and_reserved: (rand[8] & 0xf) | (Variant::RFC as u8) << 4,
-            clock_seq_low: rand[9] as u8,
+                | (Version::UNIX as u16) << 12,
+            clock_seq_high_and_reserved: Variant::RFC.stamp(rand[8]),
+            clock_seq_low: rand[9],
             node: [rand[10], rand[11], rand[12], rand[13], rand[14], rand[15]],
         }
     }
+
+    /// Generate a custom UUID carrying the CRC-32 of `payload` in its first
+    /// four bytes (`field_low`), the remaining bits are random.
+    pub fn v8_with_crc(payload: &[u8]) -> Layout {
+        let mut data = rand::random::<u128>().to_be_bytes();
+        data[..4].copy_from_slice(&crate::crc32(payload).to_be_bytes());
+        Self::v8(data)
+    }
+
+    /// Generate a custom UUID with `account` packed into its 64 most significant
+    /// payload bits, so one account's UUIDs share a prefix, followed by random bits.
+    pub fn v8_account_scoped(account: u64) -> Layout {
+        let mut data = rand::random::<u128>().to_be_bytes();
+        data[..6].copy_from_slice(&(account >> 16).to_be_bytes()[2..]);
+        data[6] = (account >> 12) as u8 & 0xf;
+        data[7] = (account >> 4) as u8;
+        data[8] = account as u8 & 0xf;
+        Self::v8(data)
+    }
+
+    /// Random bytes stamped with `version` and the RFC variant, a test fixture
+    /// for code branching on the version. Unlike the real generator of that
+    /// version, nothing is hashed or timestamped.
+    pub fn random_with_version(version: Version) -> Layout {
+        let mut layout = Self::v4();
+        layout.set_version(version);
+        layout
+    }
+
+    /// Generate a UUID from a deterministically seeded random number generator.
+    pub fn v4_from_seed(seed: u64) -> Layout {
+        Self::v4_from_rng(&mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Generate a UUID from 16 bytes drawn from the caller's random number generator.
+    pub fn v4_from_rng<R: Rng>(rng: &mut R) -> Layout {
+        let mut rand = [0u8; 16];
+        rng.fill_bytes(&mut rand);
+        Self::v4_with_bytes(rand)
+    }
+
+    /// Check whether this UUID is the one generated by `v4_from_seed` with `seed`.
+    pub fn matches_seed(&self, seed: u64) -> bool {
+        Self::v4_from_seed(seed).to_uuid() == *self
+    }
+
+    /// Generate `n` random UUIDs from a single fill of the thread RNG.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n * 16` bytes overflow `usize`.
+    pub fn v4_many(n: usize) -> Vec<UUID> {
+        let mut buf = vec![0u8; n.checked_mul(16).expect("v4_many: n too large")];
+        rand::thread_rng().fill_bytes(&mut buf);
+        buf.chunks_exact(16)
+            .map(|chunk| {
+                let mut bytes = [0u8; 16];
+                bytes.copy_from_slice(chunk);
+                Self::v4_with_bytes(bytes).to_uuid()
+            })
+            .collect()
+    }
+
+    /// An endless stream of random UUIDs, see [`UuidStream`].
+    pub fn stream() -> UuidStream {
+        UuidStream {
+            rng: StdRng::from_entropy(),
+        }
+    }
+}
+
+/// Yields a fresh random UUID (version 4) on every call to `next`, from a
+/// generator seeded once from the operating system.
+#[derive(Debug)]
+pub struct UuidStream {
+    rng: StdRng,
+}
+
+impl Iterator for UuidStream {
+    type Item = UUID;
+
+    fn next(&mut self) -> Option<UUID> {
+        Some(UUID::v4_from_rng(&mut self.rng).to_uuid())
+    }
 }
 
 /// Creates a lower `String` for UUID version-4.
+///
+/// ```
+/// use uuid_rs::{v4, UUID};
+///
+/// let string: String = v4!();
+/// let lower: String = v4!(expr:lower);
+/// assert_eq!(lower, lower.to_lowercase());
+/// assert!(string.parse::<UUID>().unwrap().is_v4());
+/// ```
 #[macro_export]
 macro_rules! v4 {
     () => {
-        format!("{}", $crate::UUID::v4().as_bytes())
+        format!("{}", $crate::UUID::v4().to_uuid())
     };
     (expr:lower) => {
-        format!("{}", $crate::UUID::v4().as_bytes().to_lower())
+        $crate::UUID::v4().to_uuid().to_string()
+    };
+}
+
+/// Creates a `UUID` version-4, the typed form of `v4!`.
+///
+/// ```
+/// #![deny(deprecated)]
+/// use uuid_rs::{v4, v4_uuid, Version, UUID};
+///
+/// let string: String = v4!();
+/// let uuid: UUID = v4_uuid!();
+/// assert_eq!(string.parse::<UUID>().unwrap().version(), Some(Version::RAND));
+/// assert_eq!(uuid.version(), Some(Version::RAND));
+/// ```
+#[macro_export]
+macro_rules! v4_uuid {
+    () => {
+        $crate::UUID::v4().to_uuid()
     };
 }
 
@@ -40,11 +196,179 @@ macro_rules! v4 {
 mod tests {
     use super::*;
 
-    #[cfg(feature = "rand")]
     #[test]
     fn test_v4() {
         let uuid = UUID::v4();
         assert_eq!(uuid.get_version(), Some(Version::RAND));
         assert_eq!(uuid.get_variant(), Some(Variant::RFC));
     }
+
+    #[deny(deprecated)]
+    #[test]
+    fn test_v4_macros() {
+        let string: String = crate::v4!();
+        let uuid: UUID = crate::v4_uuid!();
+        assert_eq!(
+            string.parse::<UUID>().unwrap().version(),
+            Some(Version::RAND)
+        );
+        assert_eq!(uuid.version(), Some(Version::RAND));
+    }
+
+    #[test]
+    #[should_panic(expected = "v4_many: n too large")]
+    fn test_v4_many_overflow() {
+        UUID::v4_many(usize::MAX / 8);
+    }
+
+    #[test]
+    fn test_v4_many_samples() {
+        for _ in 0..1000 {
+            let uuid = UUID::v4();
+            assert_eq!(uuid.get_version(), Some(Version::RAND));
+            assert_eq!(uuid.get_variant(), Some(Variant::RFC));
+            assert_eq!(uuid.get_time(), None);
+        }
+    }
+
+    #[test]
+    fn test_v4_many() {
+        use std::collections::HashSet;
+
+        let uuids = UUID::v4_many(1000);
+        assert_eq!(uuids.len(), 1000);
+        assert_eq!(uuids.iter().collect::<HashSet<_>>().len(), 1000);
+        for uuid in &uuids {
+            assert_eq!(uuid.decode().get_version(), Some(Version::RAND));
+            assert_eq!(uuid.decode().get_variant(), Some(Variant::RFC));
+        }
+        assert!(UUID::v4_many(0).is_empty());
+    }
+
+    #[test]
+    fn test_stream() {
+        use std::collections::HashSet;
+
+        let uuids: HashSet<UUID> = UUID::stream().take(1000).collect();
+        assert_eq!(uuids.len(), 1000);
+        assert!(uuids.iter().all(UUID::is_v4));
+        assert!(uuids.iter().all(UUID::is_rfc_variant));
+    }
+
+    #[test]
+    fn test_v4_with_string() {
+        let (layout, string) = UUID::v4_with_string();
+        assert_eq!(layout.get_version(), Some(Version::RAND));
+        assert_eq!(string, layout.to_uuid().to_string());
+        assert_eq!(UUID::parse_str(&string), Ok(layout.to_uuid()));
+    }
+
+    #[test]
+    fn test_v7() {
+        let first = UUID::v7();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let second = UUID::v7();
+
+        assert_eq!(first.get_version(), Some(Version::UNIX));
+        assert_eq!(first.get_variant(), Some(Variant::RFC));
+        assert!(first.to_uuid() < second.to_uuid());
+
+        let created = first.get_datetime().unwrap();
+        let delta = SystemTime::now().duration_since(created).unwrap();
+        assert!(delta < std::time::Duration::from_secs(1));
+        assert_eq!(UUID::v4().get_datetime(), None);
+    }
+
+    #[test]
+    fn test_v7_monotonic() {
+        let uuids: Vec<UUID> = (0..10_000)
+            .map(|_| UUID::v7_monotonic().to_uuid())
+            .collect();
+        let mut sorted = uuids.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted, uuids);
+
+        let uuid = uuids[0].decode();
+        assert_eq!(uuid.get_version(), Some(Version::UNIX));
+        assert_eq!(uuid.get_variant(), Some(Variant::RFC));
+        let delta = SystemTime::now()
+            .duration_since(uuid.get_datetime().unwrap())
+            .unwrap();
+        assert!(delta < std::time::Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_v7_bounds() {
+        let millis = 1_700_000_000_123;
+        let time = SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(millis);
+        let (lower, upper) = UUID::v7_bounds(time);
+        assert_eq!(lower, UUID::v7_with(millis, [0; 16]).to_uuid());
+        assert_eq!(upper, UUID::v7_with(millis, [0xff; 16]).to_uuid());
+
+        for _ in 0..1000 {
+            let uuid = UUID::v7_with(millis, rand::random::<u128>().to_be_bytes()).to_uuid();
+            assert!(lower <= uuid && uuid <= upper);
+        }
+        assert!(UUID::v7_with(millis - 1, [0xff; 16]).to_uuid() < lower);
+        assert!(UUID::v7_with(millis + 1, [0; 16]).to_uuid() > upper);
+    }
+
+    #[test]
+    fn test_v8_with_crc() {
+        let uuid = UUID::v8_with_crc(b"payload");
+        assert_eq!(uuid.get_version(), Some(Version::CUSTOM));
+        assert_eq!(uuid.to_uuid().verify_crc(b"payload"), Some(true));
+        assert_eq!(uuid.to_uuid().verify_crc(b"tampered"), Some(false));
+        assert_eq!(UUID::v4().to_uuid().verify_crc(b"payload"), None);
+    }
+
+    #[test]
+    fn test_v8_account_scoped() {
+        let account = 0x0123_4567_89ab_cdef;
+        let first = UUID::v8_account_scoped(account).to_uuid();
+        let second = UUID::v8_account_scoped(account).to_uuid();
+
+        assert_eq!(first.decode().get_version(), Some(Version::CUSTOM));
+        assert_eq!(first.get_account(), Some(account));
+        assert_eq!(first.0[..8], second.0[..8]);
+        assert_ne!(first, second);
+        assert_eq!(
+            UUID::v8_account_scoped(u64::MAX).to_uuid().get_account(),
+            Some(u64::MAX)
+        );
+        assert_eq!(UUID::v4().to_uuid().get_account(), None);
+    }
+
+    #[test]
+    fn test_random_with_version() {
+        for nibble in 1..=8 {
+            let layout = UUID::random_with_version(Version::from_u8(nibble).unwrap());
+            assert_eq!(layout.get_version(), Version::from_u8(nibble));
+            assert_eq!(layout.get_variant(), Some(Variant::RFC));
+            assert_eq!(layout.to_uuid().version_num(), nibble);
+        }
+    }
+
+    #[test]
+    fn test_v4_from_rng() {
+        let first = UUID::v4_from_rng(&mut StdRng::seed_from_u64(42)).to_uuid();
+        let second = UUID::v4_from_rng(&mut StdRng::seed_from_u64(42)).to_uuid();
+        assert_eq!(first, second);
+        assert_eq!(first, UUID::v4_from_seed(42).to_uuid());
+        assert_eq!(first.version(), Some(Version::RAND));
+        assert_eq!(first.variant(), Some(Variant::RFC));
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let next = UUID::v4_from_rng(&mut rng).to_uuid();
+        assert_eq!(next, first);
+        assert_ne!(UUID::v4_from_rng(&mut rng).to_uuid(), first);
+    }
+
+    #[test]
+    fn test_matches_seed() {
+        let uuid = UUID::v4_from_seed(42).to_uuid();
+        assert!(uuid.matches_seed(42));
+        assert!(!uuid.matches_seed(43));
+    }
 }
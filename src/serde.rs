@@ -0,0 +1,76 @@
+#![cfg(feature = "serde")]
+
+use core::fmt;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::UUID;
+
+impl Serialize for UUID {
+    /// Human-readable formats get the hyphenated string, others the 16 raw bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for UUID {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(UUIDVisitor)
+        } else {
+            deserializer.deserialize_bytes(UUIDVisitor)
+        }
+    }
+}
+
+struct UUIDVisitor;
+
+impl<'de> Visitor<'de> for UUIDVisitor {
+    type Value = UUID;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "a hyphenated UUID string or 16 bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<UUID, E> {
+        UUID::parse_str(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<UUID, E> {
+        let bytes: [u8; 16] = v
+            .try_into()
+            .map_err(|_| E::invalid_length(v.len(), &self))?;
+        Ok(UUID(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        let json = serde_json::to_string(&UUID::NAMESPACE_DNS).unwrap();
+        assert_eq!(json, r#""6ba7b810-9dad-11d1-80b4-00c04fd430c8""#);
+        assert_eq!(
+            serde_json::from_str::<UUID>(&json).unwrap(),
+            UUID::NAMESPACE_DNS
+        );
+        assert!(serde_json::from_str::<UUID>(r#""6ba7b810""#).is_err());
+    }
+
+    #[test]
+    fn test_bincode() {
+        let bytes = bincode::serialize(&UUID::NAMESPACE_URL).unwrap();
+        assert!(bytes.ends_with(&UUID::NAMESPACE_URL.0));
+        assert_eq!(
+            bincode::deserialize::<UUID>(&bytes).unwrap(),
+            UUID::NAMESPACE_URL
+        );
+    }
+}
@@ -0,0 +1,85 @@
+//! Serialize a UUID as a tagged `{"$uuid": "..."}` object, for use with
+//! `#[serde(with = "uuid_rs::serde_tagged")]`.
+
+#![cfg(feature = "serde")]
+
+use core::fmt;
+
+use alloc::string::{String, ToString};
+
+use serde::de::{self, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserializer, Serializer};
+
+use crate::UUID;
+
+/// The key holding the hyphenated UUID string.
+pub const TAG: &str = "$uuid";
+
+/// Serialize a UUID as a tagged object.
+pub fn serialize<S: Serializer>(uuid: &UUID, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(1))?;
+    map.serialize_entry(TAG, &uuid.to_string())?;
+    map.end()
+}
+
+/// Deserialize a UUID from a tagged object, rejecting any other key.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UUID, D::Error> {
+    deserializer.deserialize_map(TaggedVisitor)
+}
+
+struct TaggedVisitor;
+
+impl<'de> Visitor<'de> for TaggedVisitor {
+    type Value = UUID;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "an object with a single `{}` key", TAG)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<UUID, A::Error> {
+        let key: String = map
+            .next_key()?
+            .ok_or_else(|| de::Error::missing_field(TAG))?;
+        if key != TAG {
+            return Err(de::Error::unknown_field(&key, &[TAG]));
+        }
+
+        let value: String = map.next_value()?;
+        if map.next_key::<String>()?.is_some() {
+            return Err(de::Error::invalid_length(2, &self));
+        }
+
+        UUID::parse_str(&value).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Tagged(#[serde(with = "crate::serde_tagged")] UUID);
+
+    #[test]
+    fn test_tagged_round_trip() {
+        let json = serde_json::to_string(&Tagged(UUID::NAMESPACE_DNS)).unwrap();
+        assert_eq!(json, r#"{"$uuid":"6ba7b810-9dad-11d1-80b4-00c04fd430c8"}"#);
+
+        let tagged: Tagged = serde_json::from_str(&json).unwrap();
+        assert_eq!(tagged, Tagged(UUID::NAMESPACE_DNS));
+    }
+
+    #[test]
+    fn test_tagged_invalid() {
+        assert!(serde_json::from_str::<Tagged>(
+            r#"{"uuid":"6ba7b810-9dad-11d1-80b4-00c04fd430c8"}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<Tagged>(r#"{"$uuid":"6ba7b810"}"#).is_err());
+        assert!(
+            serde_json::from_str::<Tagged>(r#""6ba7b810-9dad-11d1-80b4-00c04fd430c8""#).is_err()
+        );
+    }
+}
@@ -1,31 +1,67 @@
 #![cfg(feature = "rand")]
-#![cfg(feature = "mac")]
 
+#[cfg(feature = "mac")]
 use mac_address as MAC;
 use rand;
+#[cfg(feature = "mac")]
+use sha1_smol::Sha1;
 
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicU16, AtomicU64, Ordering};
+use std::sync::OnceLock;
 
-use crate::{ClockSeq, Domain, Layout, Timestamp, Variant, Version, UUID};
+use crate::{ClockSeq, Layout, Timestamp, Variant, Version, TICKS_MASK, UUID};
+#[cfg(feature = "mac")]
+use crate::{Domain, Error};
 
 // Keep track of last timestamp to prevent duplicates
 static LAST_TIMESTAMP: AtomicU64 = AtomicU64::new(0);
 
+// MAC address of this host, looked up once per process
+#[cfg(feature = "mac")]
+static MAC_LOOKUP: OnceLock<Option<[u8; 6]>> = OnceLock::new();
+
+// MAC address of this host, or a node hashed from its hostname when there is none
+#[cfg(feature = "mac")]
+static MAC_ADDRESS: OnceLock<[u8; 6]> = OnceLock::new();
+
+// Random node of this process, used in place of a MAC address
+static RANDOM_NODE: OnceLock<[u8; 6]> = OnceLock::new();
+
+// Node set by the application in place of the MAC address, outside the
+// 48-bit range while unset
+#[cfg(feature = "mac")]
+const NODE_UNSET: u64 = u64::MAX;
+#[cfg(feature = "mac")]
+static NODE_OVERRIDE: AtomicU64 = AtomicU64::new(NODE_UNSET);
+
 impl UUID {
     /// Generate a time based and MAC-address UUID.
     /// Uses an optimized implementation that caches the MAC address and handles timestamp collisions.
     /// The old v1() functionality is preserved for backward compatibility.
     #[cfg(feature = "mac")]
     pub fn v1() -> Layout {
-        let mut timestamp = Timestamp::new();
-        let last = LAST_TIMESTAMP.load(Ordering::SeqCst);
-        if timestamp <= Timestamp(last) {
-            timestamp = Timestamp(last + 1);
-        }
-        LAST_TIMESTAMP.store(timestamp.0, Ordering::SeqCst);
+        Self::v1_with_node(Self::node())
+    }
 
-        let mac = Self::mac();
-        let clock_seq = Self::clock_seq_high_and_reserved(Variant::RFC as u8);
+    /// Generate a time based and MAC-address UUID, failing with
+    /// `Error::NoMacAddress` instead of falling back to a random node.
+    #[cfg(feature = "mac")]
+    pub fn try_v1() -> Result<Layout, Error> {
+        let node = Self::node_override()
+            .or_else(Self::mac_lookup)
+            .ok_or(Error::NoMacAddress)?;
+        Ok(Self::v1_with_node(node))
+    }
+
+    /// Generate a time based UUID with a random node instead of the MAC-address,
+    /// the node is drawn once per process with the multicast bit set.
+    pub fn v1_random_node() -> Layout {
+        Self::v1_with_node(*RANDOM_NODE.get_or_init(Self::random_node))
+    }
+
+    fn v1_with_node(node: [u8; 6]) -> Layout {
+        let timestamp = Self::next_timestamp();
+        let clock_seq = Self::clock_seq_high_and_reserved(Variant::RFC);
 
         Layout {
             field_low: (&timestamp & 0xffff_ffff) as u32,
@@ -34,10 +70,46 @@ impl UUID {
                 | (Version::TIME as u16) << 12,
             clock_seq_high_and_reserved: clock_seq.0,
             clock_seq_low: clock_seq.1,
-            node: mac,
+            node,
         }
     }
 
+    /// Generate a time based and MAC-address UUID with the timestamp fields
+    /// reordered most significant first, so UUIDs sort by creation time.
+    #[cfg(feature = "mac")]
+    pub fn v6() -> Layout {
+        let timestamp = Self::next_timestamp();
+
+        let clock_seq = Self::clock_seq_high_and_reserved(Variant::RFC);
+
+        Layout {
+            field_low: (&timestamp >> 28) as u32,
+            field_mid: ((&timestamp >> 12) & 0xffff) as u16,
+            field_high_and_version: (&timestamp & 0xfff) as u16 | (Version::REORDERED as u16) << 12,
+            clock_seq_high_and_reserved: clock_seq.0,
+            clock_seq_low: clock_seq.1,
+            node: Self::node(),
+        }
+    }
+
+    /// Take a timestamp strictly greater than any handed out before, advancing
+    /// `LAST_TIMESTAMP` atomically so concurrent callers never share one. Once
+    /// saturated at the largest 60-bit timestamp it repeats, see [`advance`].
+    fn next_timestamp() -> Timestamp {
+        Self::next_timestamp_at(Timestamp::new())
+    }
+
+    /// Like [`UUID::next_timestamp`] for a clock reading of `now`. A clock set
+    /// backwards restarts from `now` and advances the shared clock sequence
+    /// once more, so the next UUID skips a sequence value.
+    fn next_timestamp_at(now: Timestamp) -> Timestamp {
+        let (timestamp, backward) = advance(&LAST_TIMESTAMP, now);
+        if backward {
+            ClockSeq::new(rand::random::<u16>());
+        }
+        timestamp
+    }
+
     /// Generate a time based, MAC-address and DCE-security UUID.
     /// Preserves the old v2() functionality for backward compatibility.
     ///
@@ -45,21 +117,33 @@ impl UUID {
     /// but it does not provide any details.
     #[cfg(feature = "mac")]
     pub fn v2(d: Domain) -> Layout {
-        let mut timestamp = Timestamp::new();
-        let last = LAST_TIMESTAMP.load(Ordering::SeqCst);
-        if timestamp <= Timestamp(last) {
-            timestamp = Timestamp(last + 1);
-        }
-        LAST_TIMESTAMP.store(timestamp.0, Ordering::SeqCst);
+        let timestamp = Self::next_timestamp();
 
         Layout {
             field_low: (&timestamp & 0xffff_ffff) as u32,
             field_mid: ((&timestamp >> 32) & 0xffff) as u16,
             field_high_and_version: ((&timestamp >> 48) & 0xfff) as u16
                 | (Version::DCE as u16) << 12,
-            clock_seq_high_and_reserved: Self::clock_seq_high_and_reserved(Variant::RFC as u8).0,
+            clock_seq_high_and_reserved: Self::clock_seq_high_and_reserved(Variant::RFC).0,
+            clock_seq_low: d as u8,
+            node: Self::node(),
+        }
+    }
+
+    /// Generate a DCE-security UUID carrying a POSIX UID or GID `id` in
+    /// place of the low timestamp bits, with the domain in `clock_seq_low`.
+    #[cfg(feature = "mac")]
+    pub fn v2_with_id(d: Domain, id: u32) -> Layout {
+        let timestamp = Self::next_timestamp();
+
+        Layout {
+            field_low: id,
+            field_mid: ((&timestamp >> 32) & 0xffff) as u16,
+            field_high_and_version: ((&timestamp >> 48) & 0xfff) as u16
+                | (Version::DCE as u16) << 12,
+            clock_seq_high_and_reserved: Self::clock_seq_high_and_reserved(Variant::RFC).0,
             clock_seq_low: d as u8,
-            node: Self::mac(),
+            node: Self::node(),
         }
     }
 
@@ -69,7 +153,7 @@ impl UUID {
     #[inline]
     pub fn from_mac(v: Version, mac: [u8; 6]) -> Layout {
         let timestamp = Timestamp::new();
-        let clock_seq = Self::clock_seq_high_and_reserved(Variant::RFC as u8);
+        let clock_seq = Self::clock_seq_high_and_reserved(Variant::RFC);
         Layout {
             field_low: (&timestamp & 0xffff_ffff) as u32,
             field_mid: ((&timestamp >> 32) & 0xffff) as u16,
@@ -81,23 +165,170 @@ impl UUID {
     }
 
     /// Get random clock sequence with variant bits
-    #[cfg(feature = "rand")]
     #[inline]
-    fn clock_seq_high_and_reserved(s: u8) -> (u8, u8) {
+    fn clock_seq_high_and_reserved(v: Variant) -> (u8, u8) {
         let clock_seq = ClockSeq::new(rand::random::<u16>()).0;
-        (
-            ((clock_seq >> 8) & 0xf) as u8 | s << 4,
-            (clock_seq & 0xff) as u8,
-        )
+        (v.stamp((clock_seq >> 8) as u8), (clock_seq & 0xff) as u8)
+    }
+
+    /// Use `node` in place of the MAC address for all following version 1,
+    /// 2 and 6 UUIDs of this process.
+    #[cfg(feature = "mac")]
+    pub fn set_node(node: [u8; 6]) {
+        let mut bytes = [0u8; 8];
+        bytes[2..].copy_from_slice(&node);
+        NODE_OVERRIDE.store(u64::from_be_bytes(bytes), Ordering::SeqCst);
+    }
+
+    /// Go back to the MAC address after [`UUID::set_node`].
+    #[cfg(feature = "mac")]
+    pub fn clear_node() {
+        NODE_OVERRIDE.store(NODE_UNSET, Ordering::SeqCst);
+    }
+
+    /// The node set by the application, or else the MAC address.
+    #[cfg(feature = "mac")]
+    fn node() -> [u8; 6] {
+        Self::node_override().unwrap_or_else(Self::mac)
+    }
+
+    /// The node set by the application with [`UUID::set_node`], if any.
+    #[cfg(feature = "mac")]
+    fn node_override() -> Option<[u8; 6]> {
+        match NODE_OVERRIDE.load(Ordering::SeqCst) {
+            NODE_UNSET => None,
+            node => {
+                let mut bytes = [0u8; 6];
+                bytes.copy_from_slice(&node.to_be_bytes()[2..]);
+                Some(bytes)
+            }
+        }
     }
 
     /// Get MAC address with caching for better performance
     #[cfg(feature = "mac")]
     #[inline]
     fn mac() -> [u8; 6] {
-        // This could be further optimized with a static cached MAC address
-        // but that would require additional synchronization
-        MAC::get_mac_address().unwrap().unwrap().bytes()
+        *MAC_ADDRESS.get_or_init(|| Self::mac_or_hostname(Self::mac_lookup()))
+    }
+
+    /// Look up the MAC address of this host once per process.
+    #[cfg(feature = "mac")]
+    fn mac_lookup() -> Option<[u8; 6]> {
+        *MAC_LOOKUP.get_or_init(|| MAC::get_mac_address().ok().flatten().map(|mac| mac.bytes()))
+    }
+
+    /// Use the looked up MAC address, or a node hashed from the hostname
+    /// when none is available, so it stays stable for the host.
+    #[cfg(feature = "mac")]
+    fn mac_or_hostname(lookup: Option<[u8; 6]>) -> [u8; 6] {
+        lookup.unwrap_or_else(Self::node_from_hostname)
+    }
+
+    /// A node from the SHA1 hash of the hostname with the multicast bit set,
+    /// stable for the host without a network card (RFC 4122 section 4.5).
+    #[cfg(feature = "mac")]
+    pub fn node_from_hostname() -> [u8; 6] {
+        let mut hasher = Sha1::new();
+        hasher.update(gethostname::gethostname().as_encoded_bytes());
+        let mut node = [0u8; 6];
+        node.copy_from_slice(&hasher.digest().bytes()[..6]);
+        node[0] |= 0x01;
+        node
+    }
+
+    /// A random node with the multicast bit set, so it can't be mistaken
+    /// for a real MAC address (RFC 4122 section 4.5).
+    fn random_node() -> [u8; 6] {
+        let mut node = [0u8; 6];
+        node.copy_from_slice(&rand::random::<u64>().to_be_bytes()[2..]);
+        node[0] |= 0x01;
+        node
+    }
+}
+
+/// Serializes the tests of the whole crate which generate from, reset or
+/// expect the process-wide version 1, 2 and 6 state: the timestamp, the clock
+/// sequence and the node.
+#[cfg(test)]
+pub(crate) static STATE_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Start the process-wide version 1, 2 and 6 state over: no timestamp handed
+/// out yet and a freshly seeded clock sequence.
+#[cfg(test)]
+pub(crate) fn reset_v1_state() {
+    LAST_TIMESTAMP.store(0, Ordering::SeqCst);
+    ClockSeq::reseed();
+}
+
+/// How far, in 100-ns intervals, a clock reading may trail the last timestamp
+/// before it counts as the clock going backwards rather than a burst of calls
+/// within the same interval. A clock set back by less than this, one second,
+/// is not detected: timestamps keep counting up from the last one, faking
+/// monotonic time until the clock catches up.
+const CLOCK_BACKWARD_SLACK: u64 = 10_000_000;
+
+/// Take a timestamp strictly greater than the one last stored in `last_timestamp`,
+/// unless the clock went backwards: then restart from `now` and return `true`
+/// so the caller bumps its clock sequence (RFC 4122 section 4.1.5). Saturates
+/// at the largest 60-bit timestamp rather than spilling into the version bits,
+/// from then on every call returns that same timestamp and only the clock
+/// sequence tells the UUIDs apart.
+fn advance(last_timestamp: &AtomicU64, now: Timestamp) -> (Timestamp, bool) {
+    let now = now.0;
+    let backward = |last: u64| now.saturating_add(CLOCK_BACKWARD_SLACK) < last;
+    let next = |last: u64| {
+        if backward(last) {
+            now
+        } else {
+            now.max(last + 1).min(TICKS_MASK)
+        }
+    };
+    let last = last_timestamp
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |last| Some(next(last)))
+        .unwrap();
+    (Timestamp(next(last)), backward(last))
+}
+
+/// A version-1 generator with its own timestamp, clock sequence and node,
+/// independent of the process-wide state behind [`UUID::v1`].
+#[derive(Debug)]
+pub struct Generator {
+    last_timestamp: AtomicU64,
+    clock_seq: AtomicU16,
+    node: [u8; 6],
+}
+
+impl Generator {
+    /// Create a generator for `node`, the clock sequence starts random.
+    pub fn new(node: [u8; 6]) -> Self {
+        Self {
+            last_timestamp: AtomicU64::new(0),
+            clock_seq: AtomicU16::new(rand::random::<u16>() & 0x3fff),
+            node,
+        }
+    }
+
+    /// Generate a time based UUID from this generator's state.
+    pub fn generate(&self) -> Layout {
+        self.generate_at(Timestamp::new())
+    }
+
+    fn generate_at(&self, now: Timestamp) -> Layout {
+        let (timestamp, backward) = advance(&self.last_timestamp, now);
+        if backward {
+            self.next_clock_seq();
+        }
+        UUID::v1_exact(timestamp, self.next_clock_seq(), self.node)
+    }
+
+    /// Advance the clock sequence, returning the value before.
+    fn next_clock_seq(&self) -> u16 {
+        self.clock_seq
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |seq| {
+                Some(seq.wrapping_add(1) & 0x3fff)
+            })
+            .unwrap()
     }
 }
 
@@ -105,7 +336,7 @@ impl UUID {
 #[macro_export]
 macro_rules! v1 {
     () => {
-        format!("{}", $crate::UUID::v1().as_bytes())
+        format!("{}", $crate::UUID::v1().to_uuid())
     };
 }
 
@@ -113,17 +344,162 @@ macro_rules! v1 {
 #[macro_export]
 macro_rules! v2 {
     ($domain:expr) => {
-        format!("{}", $crate::UUID::v2($domain).as_bytes())
+        format!("{}", $crate::UUID::v2($domain).to_uuid())
+    };
+}
+
+/// Creates a `UUID` version-1, the typed form of `v1!`.
+#[macro_export]
+macro_rules! v1_uuid {
+    () => {
+        $crate::UUID::v1().to_uuid()
+    };
+}
+
+/// Creates a `UUID` version-2, the typed form of `v2!`.
+#[macro_export]
+macro_rules! v2_uuid {
+    ($domain:expr) => {
+        $crate::UUID::v2($domain).to_uuid()
     };
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "mac")]
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn test_generator() {
+        let shard_a = Generator::new([0x02, 0x00, 0x00, 0x00, 0x00, 0x0a]);
+        let shard_b = Generator::new([0x02, 0x00, 0x00, 0x00, 0x00, 0x0b]);
+
+        let a = shard_a.generate();
+        let b = shard_b.generate();
+        assert_eq!(a.get_version(), Some(Version::TIME));
+        assert_eq!(a.get_variant(), Some(Variant::RFC));
+        assert_ne!(a.get_mac().as_bytes(), b.get_mac().as_bytes());
+        assert_eq!(
+            a.get_mac().as_bytes(),
+            &[0x02, 0x00, 0x00, 0x00, 0x00, 0x0a]
+        );
+
+        let next = shard_a.generate();
+        assert!(a.get_time() < next.get_time());
+        assert_ne!(a.as_fields().3, next.as_fields().3);
+    }
+
+    #[test]
+    fn test_clock_backward() {
+        let generator = Generator::new([0x02, 0x00, 0x00, 0x00, 0x00, 0x0a]);
+        let now = Timestamp::new().0;
+        let earlier = now - 3600 * 10_000_000;
+
+        let seq = |uuid: &Layout| uuid.as_fields().3 & 0x3fff;
+        let unix = |t: u64| Some(t - crate::UTC_EPOCH);
+
+        let before = generator.generate_at(Timestamp(now));
+        let after = generator.generate_at(Timestamp(earlier));
+        assert_eq!(before.get_time(), unix(now));
+        assert_eq!(after.get_time(), unix(earlier));
+        assert_eq!(seq(&after), (seq(&before) + 2) & 0x3fff);
+
+        // Readings within the same interval still advance by one.
+        let next = generator.generate_at(Timestamp(earlier));
+        assert_eq!(next.get_time(), unix(earlier + 1));
+        assert_eq!(seq(&next), (seq(&after) + 1) & 0x3fff);
+    }
+
+    #[cfg(feature = "mac")]
+    #[test]
+    fn test_v1_clock_backward() {
+        let _guard = STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let seq = |uuid: &Layout| uuid.as_fields().3 & 0x3fff;
+        let ticks = |uuid: &Layout| uuid.get_timestamp().unwrap().value;
+
+        let before = UUID::v1();
+        let future = Timestamp::new().0 + 3600 * 10_000_000;
+        LAST_TIMESTAMP.store(future, Ordering::SeqCst);
+        let now = Timestamp::new().0;
+        let after = UUID::v1();
+
+        // Restarted from the real clock, not counting up from the future value.
+        assert!(now <= ticks(&after) && ticks(&after) < future);
+        // One sequence value per UUID plus one for the rollback, other tests
+        // drawing from the shared sequence meanwhile can only add to it.
+        assert!(seq(&after).wrapping_sub(seq(&before)) & 0x3fff >= 2);
+
+        // A reading in the same interval afterwards is no rollback.
+        let next = UUID::next_timestamp_at(Timestamp(now));
+        assert_eq!(next.0, ticks(&after) + 1);
+    }
+
+    #[test]
+    fn test_advance_saturates() {
+        let generator = Generator::new([0x02, 0x00, 0x00, 0x00, 0x00, 0x0a]);
+        generator
+            .last_timestamp
+            .store(TICKS_MASK - 1, Ordering::SeqCst);
+
+        // Saturated timestamps repeat, the clock sequence still differs.
+        let mut uuids = Vec::new();
+        for _ in 0..4 {
+            let uuid = generator.generate_at(Timestamp(TICKS_MASK - 1));
+            assert_eq!(uuid.get_version(), Some(Version::TIME));
+            assert_eq!(uuid.get_variant(), Some(Variant::RFC));
+            assert_eq!(uuid.get_time(), Some(TICKS_MASK - crate::UTC_EPOCH));
+            assert!(!uuids.contains(&uuid));
+            uuids.push(uuid);
+        }
+        assert_eq!(generator.last_timestamp.load(Ordering::SeqCst), TICKS_MASK);
+    }
+
+    #[test]
+    fn test_reset_v1_state() {
+        let _guard = STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        UUID::v1_random_node();
+
+        reset_v1_state();
+        assert_eq!(LAST_TIMESTAMP.load(Ordering::SeqCst), 0);
+        let before = Timestamp::new().0;
+        let uuid = UUID::v1_random_node();
+        let after = Timestamp::new().0;
+
+        let last = LAST_TIMESTAMP.load(Ordering::SeqCst);
+        assert!(before <= last && last <= after);
+        assert_eq!(uuid.get_time(), Some(last - crate::UTC_EPOCH));
+    }
+
+    #[test]
+    fn test_advance_burst() {
+        let last = AtomicU64::new(0);
+        let now = Timestamp::new().0;
+        let (first, backward) = advance(&last, Timestamp(now));
+        assert_eq!((first.0, backward), (now, false));
+        for i in 1..100 {
+            let (next, backward) = advance(&last, Timestamp(now));
+            assert_eq!((next.0, backward), (now + i, false));
+        }
+    }
+
+    #[test]
+    fn test_v1_random_node() {
+        let _guard = STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let first = UUID::v1_random_node();
+        let second = UUID::v1_random_node();
+
+        assert_eq!(first.get_version(), Some(Version::TIME));
+        assert_eq!(first.get_variant(), Some(Variant::RFC));
+        assert!(first.get_mac().is_multicast());
+        assert_eq!(first.get_mac().as_bytes(), second.get_mac().as_bytes());
+        assert!(first.get_time() < second.get_time());
+    }
 
     #[cfg(feature = "mac")]
     #[test]
     fn test_v1() {
+        let _guard = STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
         let uuid = UUID::v1();
 
         assert_eq!(uuid.get_version(), Some(Version::TIME));
@@ -141,20 +517,187 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "mac")]
+    #[test]
+    fn test_v1_concurrent_unique() {
+        use std::collections::HashSet;
+        use std::thread;
+
+        let _guard = STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+        let handles: Vec<_> = (0..16)
+            .map(|_| thread::spawn(|| (0..1000).map(|_| UUID::v1()).collect::<Vec<_>>()))
+            .collect();
+        let uuids: Vec<Layout> = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect();
+
+        let times: HashSet<u64> = uuids.iter().map(|uuid| uuid.get_time().unwrap()).collect();
+        assert_eq!(times.len(), uuids.len());
+        let set: HashSet<UUID> = uuids.iter().map(Layout::to_uuid).collect();
+        assert_eq!(set.len(), uuids.len());
+    }
+
+    #[cfg(feature = "mac")]
+    #[test]
+    fn test_clock_seq_stats() {
+        let _guard = STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let seqs: Vec<u16> = (0..8).map(|_| UUID::v1().as_fields().3 & 0x3fff).collect();
+        let (min, max) = UUID::clock_seq_stats();
+        assert!(min <= max && max <= 0x3fff);
+        for seq in seqs {
+            assert!(min <= seq && seq <= max);
+        }
+    }
+
+    #[cfg(feature = "mac")]
+    #[test]
+    fn test_try_v1() {
+        let _guard = STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let uuid = UUID::try_v1().unwrap();
+        assert_eq!(uuid.get_version(), Some(Version::TIME));
+        assert_eq!(
+            uuid.get_mac().0,
+            MAC::get_mac_address().unwrap().unwrap().bytes()
+        );
+    }
+
+    #[cfg(feature = "mac")]
+    #[test]
+    fn test_mac_cached() {
+        let mac = UUID::mac();
+        assert_eq!(MAC_ADDRESS.get(), Some(&mac));
+        assert_eq!(UUID::mac(), mac);
+    }
+
+    #[cfg(feature = "mac")]
+    #[test]
+    fn test_node_from_hostname() {
+        let node = UUID::node_from_hostname();
+        assert_eq!(node, UUID::node_from_hostname());
+        assert_eq!(node[0] & 0x01, 0x01);
+    }
+
+    #[cfg(feature = "mac")]
+    #[test]
+    fn test_mac_fallback() {
+        let _guard = STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let node = UUID::mac_or_hostname(None);
+        assert_eq!(node, UUID::node_from_hostname());
+        assert!(UUID::from_mac(Version::TIME, node).get_mac().is_multicast());
+
+        let mac = [0x02, 0x2a, 0x35, 0x0d, 0x13, 0x80];
+        assert_eq!(UUID::mac_or_hostname(Some(mac)), mac);
+        assert!(!UUID::from_mac(Version::TIME, mac).get_mac().is_multicast());
+    }
+
+    #[cfg(feature = "mac")]
+    #[test]
+    fn test_get_datetime() {
+        let _guard = STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let now = SystemTime::now();
+        for uuid in [UUID::v1(), UUID::v6()] {
+            let created = uuid.get_datetime().unwrap();
+            let delta = created.duration_since(now).unwrap_or_else(|e| e.duration());
+            assert!(delta < Duration::from_secs(1));
+        }
+    }
+
     #[cfg(feature = "mac")]
     #[test]
     fn test_v2() {
+        let _guard = STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
         let domain = [Domain::PERSON, Domain::GROUP, Domain::ORG];
 
         for d in domain.iter() {
             assert_eq!(UUID::v2(*d).get_version(), Some(Version::DCE));
             assert_eq!(UUID::v2(*d).get_variant(), Some(Variant::RFC));
+            assert_eq!(UUID::v2(*d).get_domain(), Some(*d));
         }
+        assert_eq!(UUID::v2(Domain::GROUP).get_domain(), Some(Domain::GROUP));
+        assert_eq!(UUID::v1().get_domain(), None);
+    }
+
+    #[cfg(feature = "mac")]
+    #[test]
+    fn test_typed_macros() {
+        let _guard = STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let uuid: UUID = crate::v1_uuid!();
+        assert!(uuid.is_v1());
+        assert!(crate::v1!().parse::<UUID>().unwrap().is_v1());
+
+        let uuid: UUID = crate::v2_uuid!(Domain::GROUP);
+        assert_eq!(uuid.decode().get_domain(), Some(Domain::GROUP));
+        let string = crate::v2!(Domain::GROUP);
+        assert_eq!(
+            string.parse::<UUID>().unwrap().version(),
+            Some(Version::DCE)
+        );
+    }
+
+    #[cfg(feature = "mac")]
+    #[test]
+    fn test_v2_with_id() {
+        let _guard = STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        for d in [Domain::PERSON, Domain::GROUP, Domain::ORG] {
+            let uuid = UUID::v2_with_id(d, 1000);
+            assert_eq!(uuid.get_version(), Some(Version::DCE));
+            assert_eq!(uuid.get_variant(), Some(Variant::RFC));
+            assert_eq!(uuid.get_domain(), Some(d));
+            assert_eq!(uuid.get_local_id(), Some(1000));
+        }
+        assert_eq!(
+            UUID::v2_with_id(Domain::GROUP, u32::MAX).get_local_id(),
+            Some(u32::MAX)
+        );
+        assert_eq!(UUID::v1().get_local_id(), None);
+    }
+
+    #[cfg(feature = "mac")]
+    #[test]
+    fn test_v6() {
+        let _guard = STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let first = UUID::v6();
+        let second = UUID::v6();
+
+        assert_eq!(first.get_version(), Some(Version::REORDERED));
+        assert_eq!(first.get_variant(), Some(Variant::RFC));
+        assert_eq!(
+            first.get_mac().0,
+            MAC::get_mac_address().unwrap().unwrap().bytes()
+        );
+        assert!(first.to_uuid() < second.to_uuid());
+    }
+
+    #[cfg(feature = "mac")]
+    #[test]
+    fn test_v1_sortable() {
+        let _guard = STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let first = UUID::v1().to_uuid();
+        let second = UUID::v1().to_uuid();
+        assert!(first.to_sortable_bytes() < second.to_sortable_bytes());
+    }
+
+    #[cfg(feature = "mac")]
+    #[test]
+    fn test_set_node() {
+        let _guard = STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let node = [0x03, 0x2a, 0x35, 0x0d, 0x13, 0x80];
+
+        UUID::set_node(node);
+        assert_eq!(UUID::v1().get_mac().0, node);
+        assert_eq!(UUID::v2(Domain::PERSON).get_mac().0, node);
+        assert_eq!(UUID::v6().get_mac().0, node);
+
+        UUID::clear_node();
+        assert_eq!(UUID::v1().get_mac().0, UUID::mac());
     }
 
     #[cfg(feature = "mac")]
     #[test]
     fn test_from_mac() {
+        let _guard = STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
         let fm = UUID::from_mac(Version::TIME, [0x03, 0x2a, 0x35, 0x0d, 0x13, 0x80]);
         assert_eq!(fm.get_version(), Some(Version::TIME));
         assert_eq!(fm.get_mac().0, [0x03, 0x2a, 0x35, 0x0d, 0x13, 0x80]);
@@ -0,0 +1,105 @@
+//! Zero-cost typed UUIDs, so IDs of different entities can't be mixed up.
+//!
+//! ```compile_fail
+//! use uuid_rs::{typed::TypedUuid, UUID};
+//!
+//! struct User;
+//! struct Order;
+//!
+//! let user: TypedUuid<User> = TypedUuid::new(UUID::nil());
+//! let order: TypedUuid<Order> = user;
+//! ```
+
+#![cfg(feature = "typed")]
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+use crate::UUID;
+
+/// A UUID tagged with the type `T` it identifies.
+pub struct TypedUuid<T> {
+    uuid: UUID,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> TypedUuid<T> {
+    /// Tag a UUID with the type `T`.
+    pub const fn new(uuid: UUID) -> Self {
+        Self {
+            uuid,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the untyped UUID.
+    pub const fn into_inner(self) -> UUID {
+        self.uuid
+    }
+}
+
+impl<T> Clone for TypedUuid<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TypedUuid<T> {}
+
+impl<T> fmt::Debug for TypedUuid<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple("TypedUuid").field(&self.uuid).finish()
+    }
+}
+
+impl<T> fmt::Display for TypedUuid<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.uuid, fmt)
+    }
+}
+
+impl<T> PartialEq for TypedUuid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.uuid == other.uuid
+    }
+}
+
+impl<T> Eq for TypedUuid<T> {}
+
+impl<T> PartialOrd for TypedUuid<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for TypedUuid<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.uuid.cmp(&other.uuid)
+    }
+}
+
+impl<T> Hash for TypedUuid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.uuid.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct User;
+
+    #[test]
+    fn test_typed_uuid() {
+        let id: TypedUuid<User> = TypedUuid::new(UUID::NAMESPACE_DNS);
+        let copy = id;
+
+        assert_eq!(id, copy);
+        assert!(TypedUuid::<User>::new(UUID::nil()) < id);
+        assert_eq!(id.into_inner(), UUID::NAMESPACE_DNS);
+        assert_eq!(format!("{}", id), format!("{}", UUID::NAMESPACE_DNS));
+    }
+}
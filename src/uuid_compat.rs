@@ -0,0 +1,36 @@
+//! Conversions to and from `uuid::Uuid` of the `uuid` crate, both types
+//! share the same 16 big-endian bytes.
+
+#![cfg(feature = "uuid-compat")]
+
+use crate::UUID;
+
+impl From<uuid::Uuid> for UUID {
+    fn from(uuid: uuid::Uuid) -> Self {
+        UUID(uuid.into_bytes())
+    }
+}
+
+impl From<UUID> for uuid::Uuid {
+    fn from(uuid: UUID) -> Self {
+        uuid::Uuid::from_bytes(uuid.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let theirs = uuid::Uuid::NAMESPACE_DNS;
+        let ours: UUID = theirs.into();
+        assert_eq!(ours, UUID::NAMESPACE_DNS);
+        assert_eq!(ours.to_string(), theirs.to_string());
+        assert_eq!(uuid::Uuid::from(ours), theirs);
+
+        for uuid in [UUID::nil(), UUID::max(), UUID::NAMESPACE_URL] {
+            assert_eq!(UUID::from(uuid::Uuid::from(uuid)), uuid);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Wiping UUIDs used as bearer tokens, so their bytes don't linger in freed
+//! memory.
+
+#![cfg(feature = "zeroize")]
+
+use core::fmt;
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::UUID;
+
+impl Zeroize for UUID {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// A UUID holding secret token material, wiped when dropped.
+pub struct SecretUUID(UUID);
+
+impl SecretUUID {
+    /// Take ownership of `uuid` as a secret.
+    pub fn new(uuid: UUID) -> Self {
+        Self(uuid)
+    }
+
+    /// Borrow the secret UUID.
+    pub fn expose(&self) -> &UUID {
+        &self.0
+    }
+}
+
+impl From<UUID> for SecretUUID {
+    fn from(uuid: UUID) -> Self {
+        Self::new(uuid)
+    }
+}
+
+impl Drop for SecretUUID {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretUUID {}
+
+impl fmt::Debug for SecretUUID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretUUID(..)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zeroize() {
+        let mut uuid = UUID::NAMESPACE_DNS;
+        uuid.zeroize();
+        assert_eq!(uuid.0, [0; 16]);
+        assert_eq!(uuid, UUID::nil());
+    }
+
+    #[test]
+    fn test_secret_uuid() {
+        let secret = SecretUUID::from(UUID::max());
+        assert_eq!(secret.expose(), &UUID::max());
+        assert_eq!(format!("{:?}", secret), "SecretUUID(..)");
+    }
+}